@@ -4,9 +4,13 @@ use std::io::Write;
 
 fn main() {
     let mut rng = rand::thread_rng();
-    let mut population: Population<4, 1, 1, 4> = Population::new(100, 20, &mut rng);
-
-    let mut champions: Vec<Individual<4, 1, 1, 4>> = Vec::new();
+    let mut population: Population<4, 1, 1, 4> = match std::fs::read_to_string("champions.json") {
+        Ok(champions) => {
+            let seeds: Vec<Individual<4, 1, 1, 4>> = serde_json::from_str(&champions).unwrap();
+            Population::from_seeds(&seeds, 100, 20, 0.2, &mut rng)
+        }
+        Err(_) => Population::new(100, 20, &mut rng),
+    };
 
     let math_problems = vec![
         ([0.0, 0.0, 0.0, 1.0], 0.0),
@@ -25,19 +29,24 @@ fn main() {
             }
         }
 
+        population.evolve(&mut rng);
+
         if i % 4 == 0 {
-            champions.push(population.individuals[0].clone());
-            println!(
-                "Generation {i:?} current fitness: {:?}",
-                population.individuals[0].fitness
-            );
+            if let Some(statistics) = population.history().latest() {
+                println!(
+                    "Generation {i:?} best fitness: {:?}, mean fitness: {:?}, species: {}",
+                    statistics.best_fitness, statistics.mean_fitness, statistics.species_count
+                );
+            }
         }
-        population.evolve(&mut rng);
     }
 
-    let string = serde_json::to_string_pretty(&champions).unwrap();
+    let string = serde_json::to_string_pretty(population.hall_of_fame.individuals()).unwrap();
     {
         let mut file = std::fs::File::create("champions.json").unwrap();
         write!(file, "{string}").unwrap();
     }
+
+    let history = serde_json::to_string_pretty(population.history()).unwrap();
+    std::fs::write("history.json", history).unwrap();
 }
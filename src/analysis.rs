@@ -0,0 +1,103 @@
+use crate::Individual;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AblationReport {
+    /// Fitness of the unmodified individual
+    pub baseline: f32,
+    /// Fitness with each input disconnected, indexed by input
+    pub ablated: Vec<f32>,
+}
+
+impl AblationReport {
+    /**
+     * Change in fitness caused by disconnecting each input, negative values
+     * mean the input was helping
+     */
+    pub fn changes(&self) -> Vec<f32> {
+        self.ablated
+            .iter()
+            .map(|fitness| fitness - self.baseline)
+            .collect()
+    }
+
+    /**
+     * Inputs ordered from the one whose removal costs the most fitness to the
+     * one costing the least
+     */
+    pub fn ranking(&self) -> Vec<usize> {
+        let mut inputs: Vec<usize> = (0..self.ablated.len()).collect();
+        inputs.sort_by(|a, b| self.ablated[*a].total_cmp(&self.ablated[*b]));
+        inputs
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Mean absolute change of the outputs per unit change of each input,
+     * estimated with central differences of size `epsilon` around every
+     * sample and summed over the outputs
+     */
+    pub fn input_sensitivity(&self, samples: &[[f32; INPUTS]], epsilon: f32) -> [f32; INPUTS] {
+        let mut sensitivity = [0.0; INPUTS];
+        if samples.is_empty() {
+            return sensitivity;
+        }
+
+        for sample in samples {
+            for (input, total) in sensitivity.iter_mut().enumerate() {
+                let mut above = *sample;
+                above[input] += epsilon;
+                let mut below = *sample;
+                below[input] -= epsilon;
+
+                *total += self
+                    .evaluate(above)
+                    .into_iter()
+                    .zip(self.evaluate(below))
+                    .map(|(a, b)| (a - b).abs() / (2.0 * epsilon))
+                    .sum::<f32>();
+            }
+        }
+
+        sensitivity.map(|total| total / samples.len() as f32)
+    }
+
+    /**
+     * Sum over every path from each input to the outputs of the product of
+     * absolute weights along the path. This is an upper bound on how strongly
+     * the input can drive the outputs that ignores the activations.
+     */
+    pub fn path_attribution(&self) -> [f32; INPUTS] {
+        let paths = self
+            .layers()
+            .into_iter()
+            .map(|layer| layer.abs())
+            .reduce(|paths, layer| paths * layer)
+            .expect("There is always an input and output matrix");
+
+        let mut attribution = [0.0; INPUTS];
+        for (input, total) in attribution.iter_mut().enumerate() {
+            *total = paths.row(input).sum();
+        }
+        attribution
+    }
+
+    /**
+     * Evaluates `fitness` on this individual and on copies with each input's
+     * connections removed, which is the same as that input always being zero
+     */
+    pub fn ablation_report<F: FnMut(&Self) -> f32>(&self, mut fitness: F) -> AblationReport {
+        let baseline = fitness(self);
+        let ablated = (0..INPUTS)
+            .map(|input| {
+                let mut ablated = self.clone();
+                ablated.input_matrix.row_mut(input).fill(0.0);
+                fitness(&ablated)
+            })
+            .collect();
+
+        AblationReport { baseline, ablated }
+    }
+}
@@ -0,0 +1,151 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{Individual, Population};
+
+/**
+ * Which islands receive migrants from which
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Topology {
+    /// Every island sends migrants to the next one
+    Ring,
+    /// Every island sends migrants to every other island
+    FullyConnected,
+    /// Every island sends migrants to one other island picked at random
+    Random,
+}
+
+/**
+ * Several independently configured populations that evolve separately and
+ * periodically exchange their best individuals
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Archipelago<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+> {
+    pub islands: Vec<Population<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    pub topology: Topology,
+    pub migration_interval: usize,
+    pub migration_size: usize,
+    generation: usize,
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Archipelago<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    pub fn new(
+        islands: Vec<Population<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+        topology: Topology,
+        migration_interval: usize,
+        migration_size: usize,
+    ) -> Self {
+        Self {
+            islands,
+            topology,
+            migration_interval,
+            migration_size,
+            generation: 0,
+        }
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /**
+     * Every individual of every island, for evaluation
+     */
+    pub fn individuals_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        self.islands
+            .iter_mut()
+            .flat_map(|island| island.individuals.iter_mut())
+    }
+
+    pub fn best(&self) -> Option<&Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        self.islands
+            .iter()
+            .flat_map(|island| island.individuals.iter())
+            .max_by(|i, j| i.fitness.total_cmp(&j.fitness))
+    }
+
+    /**
+     * Evolves every island, migrating first every `migration_interval`
+     * generations. Expects all individuals to be evaluated.
+     */
+    pub fn evolve<RNG: Rng>(&mut self, rng: &mut RNG) {
+        self.generation += 1;
+        if self.migration_interval > 0 && self.generation % self.migration_interval == 0 {
+            self.migrate(rng);
+        }
+
+        for island in self.islands.iter_mut() {
+            island.evolve(rng);
+        }
+    }
+
+    fn destinations<RNG: Rng>(&self, source: usize, rng: &mut RNG) -> Vec<usize> {
+        let count = self.islands.len();
+        match self.topology {
+            Topology::Ring => vec![(source + 1) % count],
+            Topology::FullyConnected => (0..count).filter(|&i| i != source).collect(),
+            Topology::Random => {
+                let destination = rng.gen_range(0..count - 1);
+                vec![if destination >= source {
+                    destination + 1
+                } else {
+                    destination
+                }]
+            }
+        }
+    }
+
+    /**
+     * Copies the `migration_size` fittest individuals of every island over the
+     * least fit individuals of its destinations. An island takes in at most
+     * `migration_size` migrants, the fittest of those it receives, and always
+     * keeps its own `migration_size` fittest individuals.
+     */
+    pub fn migrate<RNG: Rng>(&mut self, rng: &mut RNG) {
+        if self.islands.len() < 2 {
+            return;
+        }
+
+        let mut incoming = vec![Vec::new(); self.islands.len()];
+        for (source, island) in self.islands.iter().enumerate() {
+            let mut emigrants = island.individuals.clone();
+            emigrants.sort_by(|i, j| (-i.fitness).total_cmp(&-j.fitness));
+            emigrants.truncate(self.migration_size);
+            // Their success already counts for the island they were bred on
+            for emigrant in emigrants.iter_mut() {
+                emigrant.clear_lineage();
+            }
+
+            for destination in self.destinations(source, rng) {
+                incoming[destination].extend(emigrants.iter().cloned());
+            }
+        }
+
+        for (island, mut migrants) in self.islands.iter_mut().zip(incoming) {
+            let places = island
+                .individuals
+                .len()
+                .saturating_sub(self.migration_size)
+                .min(self.migration_size);
+            migrants.sort_by(|i, j| (-i.fitness).total_cmp(&-j.fitness));
+            migrants.truncate(places);
+
+            island
+                .individuals
+                .sort_by(|i, j| i.fitness.total_cmp(&j.fitness));
+            for (resident, migrant) in island.individuals.iter_mut().zip(migrants) {
+                *resident = migrant;
+            }
+        }
+    }
+}
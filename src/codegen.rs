@@ -0,0 +1,155 @@
+//! Generation of standalone source code evaluating an individual.
+//!
+//! The weights are written as literals and zero weights are left out. Every
+//! neuron is a single left-to-right sum in input order, which is the order
+//! nalgebra accumulates in, so the generated Rust matches `evaluate` exactly.
+
+use std::fmt::Write;
+
+use crate::Individual;
+
+/**
+ * Sum of `input[k] * weight` for every non-zero weight in the column, with
+ * `literal` formatting the weights for the target language
+ */
+fn weighted_sum(
+    input: &str,
+    column: nalgebra::DVectorView<f32>,
+    literal: impl Fn(f32) -> String,
+) -> String {
+    let terms: Vec<String> = column
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| **weight != 0.0)
+        .map(|(k, weight)| format!("{}[{}] * {}", input, k, literal(*weight)))
+        .collect();
+
+    if terms.is_empty() {
+        literal(0.0)
+    } else {
+        terms.join(" + ")
+    }
+}
+
+fn rust_literal(value: f32) -> String {
+    format!("{:?}", value)
+}
+
+fn c_literal(value: f32) -> String {
+    format!("{:?}f", value)
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Rust source of a dependency free function `name` computing `evaluate`
+     */
+    pub fn to_rust_source(&self, name: &str) -> String {
+        let layers = self.layers();
+        let mut source = String::new();
+
+        writeln!(source, "// Generated by evolution_rust, do not edit").unwrap();
+        writeln!(source, "#[rustfmt::skip]").unwrap();
+        writeln!(source, "#[allow(clippy::all)]").unwrap();
+        writeln!(
+            source,
+            "pub fn {}(layer_0: [f32; {}]) -> [f32; {}] {{",
+            name, INPUTS, OUTPUTS
+        )
+        .unwrap();
+        writeln!(
+            source,
+            "    fn relu(k: f32) -> f32 {{ if k > 0.0 {{ k }} else {{ k / 2.0 }} }}"
+        )
+        .unwrap();
+        writeln!(
+            source,
+            "    fn sigmoid(k: f32) -> f32 {{ 1.0 / (1.0 + k.exp()) }}"
+        )
+        .unwrap();
+
+        for (index, layer) in layers.iter().enumerate() {
+            let input = format!("layer_{}", index);
+            let last = index + 1 == layers.len();
+            if last {
+                writeln!(source, "    [").unwrap();
+            } else {
+                writeln!(source, "    let layer_{} = [", index + 1).unwrap();
+            }
+            for column in layer.column_iter() {
+                let sum = weighted_sum(&input, column, rust_literal);
+                let activation = if last { "sigmoid" } else { "relu" };
+                writeln!(source, "        {}({}),", activation, sum).unwrap();
+            }
+            if last {
+                writeln!(source, "    ]").unwrap();
+            } else {
+                writeln!(source, "    ];").unwrap();
+            }
+        }
+
+        writeln!(source, "}}").unwrap();
+        source
+    }
+
+    /**
+     * C source of a function `name` computing `evaluate`, only depending on
+     * `expf` from math.h. The results match exactly when the compiler does not
+     * contract the sums into fused multiply-adds, GCC ignores the pragma and
+     * needs `-ffp-contract=off`, and `expf` is the one Rust links against.
+     */
+    pub fn to_c_source(&self, name: &str) -> String {
+        let layers = self.layers();
+        let mut source = String::new();
+
+        writeln!(source, "/* Generated by evolution_rust, do not edit */").unwrap();
+        writeln!(source, "#include <math.h>").unwrap();
+        writeln!(source).unwrap();
+        writeln!(source, "#pragma STDC FP_CONTRACT OFF").unwrap();
+        writeln!(source).unwrap();
+        writeln!(
+            source,
+            "static float {}_relu(float k) {{ return k > 0.0f ? k : k / 2.0f; }}",
+            name
+        )
+        .unwrap();
+        writeln!(
+            source,
+            "static float {}_sigmoid(float k) {{ return 1.0f / (1.0f + expf(k)); }}",
+            name
+        )
+        .unwrap();
+        writeln!(source).unwrap();
+        writeln!(
+            source,
+            "void {}(const float layer_0[{}], float outputs[{}]) {{",
+            name, INPUTS, OUTPUTS
+        )
+        .unwrap();
+
+        for (index, layer) in layers.iter().enumerate() {
+            let input = format!("layer_{}", index);
+            let last = index + 1 == layers.len();
+            let (output, activation) = if last {
+                ("outputs".to_string(), "sigmoid")
+            } else {
+                let output = format!("layer_{}", index + 1);
+                writeln!(source, "    float {}[{}];", output, SUBLAYERS).unwrap();
+                (output, "relu")
+            };
+            for (neuron, column) in layer.column_iter().enumerate() {
+                let sum = weighted_sum(&input, column, c_literal);
+                writeln!(
+                    source,
+                    "    {}[{}] = {}_{}({});",
+                    output, neuron, name, activation, sum
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(source, "}}").unwrap();
+        source
+    }
+}
@@ -0,0 +1,158 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{Individual, Population};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DifferentialStrategy {
+    /// DE/rand/1/bin, the base vector is a random member of the population
+    Rand1Bin,
+    /// DE/best/1/bin, the base vector is the fittest member of the population
+    Best1Bin,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DifferentialEvolution {
+    pub strategy: DifferentialStrategy,
+    pub differential_weight: f32,
+    pub crossover_rate: f32,
+}
+
+impl Default for DifferentialEvolution {
+    fn default() -> Self {
+        Self {
+            strategy: DifferentialStrategy::Rand1Bin,
+            differential_weight: 0.5,
+            crossover_rate: 0.9,
+        }
+    }
+}
+
+/**
+ * Picks an index that is not in `excluded`
+ */
+fn distinct_index<RNG: Rng>(len: usize, excluded: &[usize], rng: &mut RNG) -> usize {
+    loop {
+        let index = rng.gen_range(0..len);
+        if !excluded.contains(&index) {
+            return index;
+        }
+    }
+}
+
+impl DifferentialEvolution {
+    fn trial_vector<RNG: Rng>(
+        &self,
+        targets: &[Vec<f32>],
+        best: usize,
+        index: usize,
+        rng: &mut RNG,
+    ) -> Vec<f32> {
+        let base = match self.strategy {
+            DifferentialStrategy::Rand1Bin => distinct_index(targets.len(), &[index], rng),
+            DifferentialStrategy::Best1Bin => best,
+        };
+        let first = distinct_index(targets.len(), &[index, base], rng);
+        let second = distinct_index(targets.len(), &[index, base, first], rng);
+
+        let target = &targets[index];
+        let forced = rng.gen_range(0..target.len());
+
+        (0..target.len())
+            .map(|j| {
+                if j == forced || rng.gen::<f32>() < self.crossover_rate {
+                    targets[base][j]
+                        + self.differential_weight * (targets[first][j] - targets[second][j])
+                } else {
+                    target[j]
+                }
+            })
+            .collect()
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Population<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Differential evolution step, an alternative to `evolve`.
+     *
+     * Expects the current individuals to be evaluated. Each of them is a trial
+     * vector that replaces its `trial_target` in `parents` if it is at least as
+     * fit, after which a new set of trial vectors is generated from the
+     * targets. The individuals can be reordered in between. On the first call,
+     * or when an individual is not a trial vector of the current targets, the
+     * evaluated individuals become the targets. When `max_size` changes the
+     * least fit targets are dropped or the targets are duplicated, keeping at
+     * least 4 of them. The mutation schedules are not used, `settings` controls
+     * the variation.
+     */
+    pub fn evolve_differential<RNG: Rng>(
+        &mut self,
+        settings: &DifferentialEvolution,
+        rng: &mut RNG,
+    ) {
+        self.apply_scheduled_changes();
+        self.record_generation();
+        // Every trial vector needs three other targets
+        let size = self.max_size.max(4);
+
+        let trials_of_parents = !self.parents.is_empty()
+            && self.individuals.iter().all(|trial| {
+                trial
+                    .trial_target
+                    .is_some_and(|target| target < self.parents.len())
+            });
+        if trials_of_parents {
+            for trial in self.individuals.iter() {
+                if let Some(index) = trial.trial_target {
+                    if trial.fitness >= self.parents[index].fitness {
+                        self.parents[index] = trial.clone();
+                    }
+                }
+            }
+        } else {
+            self.parents = self.individuals.clone();
+        }
+
+        if self.parents.is_empty() {
+            self.individuals = (0..size)
+                .map(|_| Individual::new_initialized(&self.initializer, rng))
+                .collect();
+            self.finish_generation();
+            return;
+        }
+
+        if self.parents.len() > size {
+            self.parents
+                .sort_by(|i, j| (-i.fitness).total_cmp(&-j.fitness));
+            self.parents.truncate(size);
+        }
+        for i in self.parents.len()..size {
+            self.parents
+                .push(self.parents[i % self.parents.len()].clone());
+        }
+        for target in self.parents.iter_mut() {
+            target.clear_lineage();
+        }
+
+        let best = (0..self.parents.len())
+            .max_by(|&i, &j| self.parents[i].fitness.total_cmp(&self.parents[j].fitness))
+            .unwrap_or(0);
+        let targets: Vec<_> = self.parents.iter().map(Individual::weights).collect();
+
+        self.individuals = self
+            .parents
+            .iter()
+            .enumerate()
+            .map(|(index, target)| {
+                let mut trial = target.clone();
+                trial.set_weights(&settings.trial_vector(&targets, best, index, rng));
+                trial.reset_evaluation();
+                trial.trial_target = Some(index);
+                trial
+            })
+            .collect();
+        self.finish_generation();
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Individual;
+
+/**
+ * How the distance between two genomes is measured
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// Sum of squared weight differences, what `Individual::similarity` computes
+    #[default]
+    SquaredEuclidean,
+    /// `SquaredEuclidean` divided by the number of weights
+    Normalized,
+    /// One minus the cosine similarity of the weight vectors
+    Cosine,
+    /// Fraction of weights that are zero in one individual but not the other
+    Structural,
+    /// Weighted sum of the `Structural` distance and the mean absolute weight
+    /// difference of the connections both individuals share
+    Weighted { structural: f32, weights: f32 },
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    pub fn distance(&self, other: &Self, metric: DistanceMetric) -> f32 {
+        let count = Self::WEIGHT_COUNT.max(1) as f32;
+        let pairs = || self.weights().into_iter().zip(other.weights());
+
+        match metric {
+            DistanceMetric::SquaredEuclidean => self.similarity(other),
+            DistanceMetric::Normalized => self.similarity(other) / count,
+            DistanceMetric::Cosine => {
+                let (dot, one, two) = pairs().fold((0.0, 0.0, 0.0), |(dot, one, two), (a, b)| {
+                    (dot + a * b, one + a * a, two + b * b)
+                });
+                if one == 0.0 && two == 0.0 {
+                    0.0
+                } else if one == 0.0 || two == 0.0 {
+                    1.0
+                } else {
+                    1.0 - dot / (one.sqrt() * two.sqrt())
+                }
+            }
+            DistanceMetric::Structural => {
+                pairs().filter(|(a, b)| (*a == 0.0) != (*b == 0.0)).count() as f32 / count
+            }
+            DistanceMetric::Weighted {
+                structural,
+                weights,
+            } => {
+                let (mismatched, difference, shared) = pairs().fold(
+                    (0, 0.0, 0),
+                    |(mismatched, difference, shared), (a, b)| match (a == 0.0, b == 0.0) {
+                        (false, false) => (mismatched, difference + (a - b).abs(), shared + 1),
+                        (true, true) => (mismatched, difference, shared),
+                        _ => (mismatched + 1, difference, shared),
+                    },
+                );
+                structural * mismatched as f32 / count + weights * difference / shared.max(1) as f32
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{distance::DistanceMetric, Individual, Population};
+
+/**
+ * Mean distance over all pairs of individuals
+ */
+pub fn mean_pairwise_distance<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+>(
+    individuals: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+    metric: DistanceMetric,
+) -> f32 {
+    let mut distance = 0.0;
+    let mut pairs = 0;
+    for (index, one) in individuals.iter().enumerate() {
+        for two in &individuals[index + 1..] {
+            distance += one.distance(two, metric);
+            pairs += 1;
+        }
+    }
+    distance / pairs.max(1) as f32
+}
+
+/**
+ * Shannon entropy in bits of the distribution of every weight over the
+ * population, each weight's range split into `bins` buckets, averaged over all
+ * weights
+ */
+pub fn weight_entropy<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+>(
+    individuals: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+    bins: usize,
+) -> f32 {
+    let weights: Vec<Vec<f32>> = individuals.iter().map(Individual::weights).collect();
+    if weights.is_empty() || bins == 0 {
+        return 0.0;
+    }
+
+    let mut total = 0.0;
+    for position in 0..Individual::<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>::WEIGHT_COUNT {
+        let values: Vec<f32> = weights.iter().map(|i| i[position]).collect();
+        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        if max <= min {
+            continue;
+        }
+
+        let mut counts = vec![0; bins];
+        for value in values {
+            let bin = ((value - min) / (max - min) * bins as f32) as usize;
+            counts[bin.min(bins - 1)] += 1;
+        }
+        total += entropy(&counts);
+    }
+    total / Individual::<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>::WEIGHT_COUNT.max(1) as f32
+}
+
+/**
+ * Shannon entropy in bits of the species size distribution
+ */
+pub fn species_entropy(species_sizes: &[usize]) -> f32 {
+    entropy(species_sizes)
+}
+
+fn entropy(counts: &[usize]) -> f32 {
+    let total = counts.iter().sum::<usize>() as f32;
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let probability = *count as f32 / total;
+            -probability * probability.log2()
+        })
+        .sum()
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DiversityMetrics {
+    pub mean_pairwise_distance: f32,
+    pub weight_entropy: f32,
+    pub species_entropy: f32,
+}
+
+/**
+ * What `evolve` does to the new generation when diversity is too low
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum DiversityResponse {
+    /// Replaces this fraction of the offspring with random individuals
+    RandomImmigrants { fraction: f32 },
+    /// Applies this many extra mutations to every offspring
+    Hypermutation { mutations: usize },
+    /// Keeps up to this many species champions, best first, and replaces
+    /// everything else with random individuals. Offspring never survive.
+    Restart { survivors: usize },
+}
+
+/**
+ * Triggers `response` when the mean pairwise distance of the evaluated
+ * generation falls below `threshold`
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DiversityControl {
+    pub threshold: f32,
+    pub response: DiversityResponse,
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Population<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    pub fn diversity_metrics(&self, bins: usize) -> DiversityMetrics {
+        let mut individuals = self.individuals.clone();
+        individuals.sort_by(|i, j| (-i.score()).total_cmp(&-j.score()));
+        let species_sizes: Vec<usize> = self.speciate(&individuals).iter().map(Vec::len).collect();
+
+        DiversityMetrics {
+            mean_pairwise_distance: mean_pairwise_distance(&self.individuals, self.distance_metric),
+            weight_entropy: weight_entropy(&self.individuals, bins),
+            species_entropy: species_entropy(&species_sizes),
+        }
+    }
+
+    /**
+     * Applies the configured `diversity_control` to a new generation whose first
+     * `elites` individuals are the unchanged species champions
+     */
+    pub(crate) fn control_diversity<RNG: Rng>(
+        &self,
+        new_individuals: &mut [Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+        elites: usize,
+        rng: &mut RNG,
+    ) {
+        let (Some(control), Some(statistics)) = (self.diversity_control, self.history.latest())
+        else {
+            return;
+        };
+        if statistics.mean_genome_distance >= control.threshold {
+            return;
+        }
+
+        let len = new_individuals.len();
+        match control.response {
+            DiversityResponse::RandomImmigrants { fraction } => {
+                let offspring = len.saturating_sub(elites);
+                let immigrants = ((offspring as f32 * fraction).round() as usize).min(offspring);
+                for individual in new_individuals.iter_mut().skip(len - immigrants) {
+                    *individual = Individual::new_initialized(&self.initializer, rng);
+                }
+            }
+            DiversityResponse::Hypermutation { mutations } => {
+                let strength = self.mutation_strength.value(self.generation);
+                for individual in new_individuals.iter_mut().skip(elites) {
+                    for _ in 0..mutations {
+                        individual.mutate_with_strength(strength, rng);
+                    }
+                }
+            }
+            DiversityResponse::Restart { survivors } => {
+                let survivors = survivors.min(elites).min(len);
+                for individual in new_individuals.iter_mut().skip(survivors) {
+                    *individual = Individual::new_initialized(&self.initializer, rng);
+                }
+            }
+        }
+    }
+}
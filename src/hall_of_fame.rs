@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{distance::DistanceMetric, Individual};
+
+/**
+ * The best individuals ever seen, fittest first. Candidates closer than
+ * `min_distance` to a member, measured with `metric`, count as the same
+ * individual and only replace it when they are fitter.
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct HallOfFame<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+> {
+    pub capacity: usize,
+    pub min_distance: f32,
+    #[serde(default)]
+    pub metric: DistanceMetric,
+    individuals: Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize> Default
+    for HallOfFame<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    fn default() -> Self {
+        Self::new(10, 0.5)
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    HallOfFame<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    pub fn new(capacity: usize, min_distance: f32) -> Self {
+        Self {
+            capacity,
+            min_distance,
+            metric: DistanceMetric::default(),
+            individuals: Vec::new(),
+        }
+    }
+
+    pub fn individuals(&self) -> &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>] {
+        &self.individuals
+    }
+
+    pub fn best(&self) -> Option<&Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        self.individuals.first()
+    }
+
+    /**
+     * Returns whether the candidate was admitted
+     */
+    pub fn offer(&mut self, candidate: &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>) -> bool {
+        match self
+            .individuals
+            .iter()
+            .position(|i| i.distance(candidate, self.metric) < self.min_distance)
+        {
+            Some(twin) if self.individuals[twin].fitness >= candidate.fitness => return false,
+            Some(twin) => {
+                self.individuals.remove(twin);
+            }
+            None if self.individuals.len() >= self.capacity => match self.individuals.last() {
+                Some(worst) if worst.fitness < candidate.fitness => {
+                    self.individuals.pop();
+                }
+                _ => return false,
+            },
+            None => (),
+        }
+
+        let position = self
+            .individuals
+            .partition_point(|i| i.fitness >= candidate.fitness);
+        self.individuals.insert(position, candidate.clone());
+        true
+    }
+
+    /**
+     * Offers every candidate, returns how many were admitted
+     */
+    pub fn update<'a>(
+        &mut self,
+        candidates: impl IntoIterator<Item = &'a Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    ) -> usize {
+        candidates
+            .into_iter()
+            .filter(|candidate| self.offer(candidate))
+            .count()
+    }
+}
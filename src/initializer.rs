@@ -0,0 +1,76 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::EvolutionMatrix;
+
+/**
+ * How the weights of a new random individual are chosen. Fan-in and fan-out
+ * are the row and column counts of each matrix.
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Initializer {
+    /// Each weight is non-zero with probability `density`, drawn uniformly from `-range..=range`
+    Sparse { density: f64, range: f32 },
+    /// Every weight drawn uniformly from `-range..=range`
+    Dense { range: f32 },
+    /// Xavier/Glorot uniform, `sqrt(6 / (fan_in + fan_out))` range
+    Xavier,
+    /// He normal, standard deviation `sqrt(2 / fan_in)`
+    He,
+    /// Normal distribution with mean zero
+    Gaussian { sigma: f32 },
+    /// No connections at all, structure has to be grown by mutation
+    Zero,
+}
+
+impl Default for Initializer {
+    fn default() -> Self {
+        Initializer::Sparse {
+            density: 0.25,
+            range: 1.0,
+        }
+    }
+}
+
+/**
+ * Standard normal sample using the Box-Muller transform
+ */
+pub(crate) fn gaussian<RNG: Rng>(rng: &mut RNG) -> f32 {
+    let u1: f32 = 1.0 - rng.gen::<f32>();
+    let u2: f32 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+}
+
+impl Initializer {
+    pub fn matrix<const INPUT: usize, const OUTPUT: usize, RNG: Rng>(
+        &self,
+        rng: &mut RNG,
+    ) -> EvolutionMatrix<INPUT, OUTPUT> {
+        let fan_in = INPUT as f32;
+        let fan_out = OUTPUT as f32;
+
+        let mut matrix = EvolutionMatrix::<INPUT, OUTPUT>::zeros();
+        for i in 0..INPUT {
+            for j in 0..OUTPUT {
+                matrix[(i, j)] = match *self {
+                    Initializer::Sparse { density, range } => {
+                        if rng.gen_bool(density) {
+                            rng.gen_range(-range..=range)
+                        } else {
+                            0.0
+                        }
+                    }
+                    Initializer::Dense { range } => rng.gen_range(-range..=range),
+                    Initializer::Xavier => {
+                        let range = (6.0 / (fan_in + fan_out)).sqrt();
+                        rng.gen_range(-range..=range)
+                    }
+                    Initializer::He => gaussian(rng) * (2.0 / fan_in).sqrt(),
+                    Initializer::Gaussian { sigma } => gaussian(rng) * sigma,
+                    Initializer::Zero => 0.0,
+                };
+            }
+        }
+        matrix
+    }
+}
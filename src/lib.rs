@@ -1,6 +1,37 @@
+pub mod analysis;
+pub mod archipelago;
+pub mod codegen;
+pub mod differential_evolution;
+pub mod distance;
+pub mod diversity;
+pub mod hall_of_fame;
+pub mod initializer;
+pub mod map_elites;
+pub mod novelty;
+pub mod nsga2;
+pub mod observer;
+pub mod onnx;
+pub mod pruning;
+pub mod quantization;
+pub mod schedule;
+pub mod self_adaptation;
 mod serde_arrays;
+pub mod space;
+pub mod speciation;
+pub mod statistics;
+pub mod visualization;
 
+use distance::DistanceMetric;
+use diversity::DiversityControl;
+use hall_of_fame::HallOfFame;
+use initializer::Initializer;
+use novelty::NoveltyArchive;
+use observer::{EvolutionObserver, Observers};
+use schedule::Schedule;
+use self_adaptation::MutationMode;
 use serde::{Deserialize, Serialize};
+use speciation::{output_distance, ProbeSet, Speciation};
+use statistics::{GenerationStatistics, History};
 
 pub type EvolutionMatrix<const INPUT: usize, const OUTPUT: usize> = nalgebra::Matrix<
     f32,
@@ -22,6 +53,52 @@ pub struct Individual<
     pub matricies: [EvolutionMatrix<SUBLAYERS, SUBLAYERS>; LAYERS],
     pub output_matrix: EvolutionMatrix<SUBLAYERS, OUTPUTS>,
     pub fitness: f32,
+
+    /**
+     * Objective values for multi-objective evolution, higher is better
+     */
+    #[serde(default)]
+    pub objectives: Vec<f32>,
+
+    /**
+     * Behavior descriptor used by novelty search, filled in by the evaluation
+     */
+    #[serde(default)]
+    pub behavior: Vec<f32>,
+
+    /**
+     * Self-adaptive mutation step size of every matrix, empty until the first
+     * self-adaptive mutation
+     */
+    #[serde(default)]
+    pub step_sizes: Vec<f32>,
+
+    /**
+     * Fitness of the parent this individual was mutated from, `None` for
+     * random individuals and survivors. Drives the 1/5th success rule.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_fitness: Option<f32>,
+
+    /**
+     * Index into `Population::parents` of the target this trial vector of
+     * differential evolution competes with
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trial_target: Option<usize>,
+
+    /**
+     * Novelty of the behavior, set by `Population::apply_novelty`
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub novelty: Option<f32>,
+
+    /**
+     * What `evolve` selects on instead of `fitness` when set, the blend of
+     * fitness and novelty from `Population::apply_novelty`
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selection_score: Option<f32>,
 }
 
 fn matrix_similarity<const INPUT: usize, const OUTPUT: usize>(
@@ -45,26 +122,22 @@ fn sigmoid<const SIZE: usize>(matrix: EvolutionMatrix<1, SIZE>) -> EvolutionMatr
     matrix.map(|k| 1.0 / (1.0 + k.exp()))
 }
 
-fn random_matrix<const INPUT: usize, const OUTPUT: usize, RNG: rand::Rng>(
-    rng: &mut RNG,
-) -> EvolutionMatrix<INPUT, OUTPUT> {
-    let mut matrix = EvolutionMatrix::<INPUT, OUTPUT>::zeros();
-
-    for i in 0..INPUT {
-        for j in 0..OUTPUT {
-            if rng.gen_bool(0.25) {
-                matrix[(i, j)] = rng.gen_range(-1.0..=1.0);
-            }
-        }
-    }
-
-    return matrix;
-}
-
 fn mutate_matrix<const INPUT: usize, const OUTPUT: usize, RNG: rand::Rng>(
     matrix: &mut EvolutionMatrix<INPUT, OUTPUT>,
+    strength: f32,
     rng: &mut RNG,
 ) {
+    let position = pick_weight(matrix, rng);
+    matrix[position] += rng.gen_range(-strength..=strength);
+}
+
+/**
+ * A random position in the matrix, preferring weights that are already non-zero
+ */
+fn pick_weight<const INPUT: usize, const OUTPUT: usize, RNG: rand::Rng>(
+    matrix: &EvolutionMatrix<INPUT, OUTPUT>,
+    rng: &mut RNG,
+) -> (usize, usize) {
     let mut attempts = 0;
 
     let mut x = rng.gen_range(0..INPUT);
@@ -75,12 +148,15 @@ fn mutate_matrix<const INPUT: usize, const OUTPUT: usize, RNG: rand::Rng>(
         attempts += 1;
     }
 
-    matrix[(x, y)] += rng.gen_range(-0.1..=0.1);
+    (x, y)
 }
 
 impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
     Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
 {
+    pub const WEIGHT_COUNT: usize =
+        INPUTS * SUBLAYERS + LAYERS * SUBLAYERS * SUBLAYERS + SUBLAYERS * OUTPUTS;
+
     pub fn evaluate(&self, inputs: [f32; INPUTS]) -> [f32; OUTPUTS] {
         let layer_1 = EvolutionMatrix::<1, INPUTS>::from_row_slice(&inputs);
         let layer_2 = relu(layer_1 * self.input_matrix);
@@ -99,34 +175,170 @@ impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLA
             + matrix_similarity(&self.output_matrix, &other.output_matrix);
     }
 
+    /**
+     * Number of non-zero weights
+     */
+    pub fn connection_count(&self) -> usize {
+        self.weights()
+            .iter()
+            .filter(|weight| **weight != 0.0)
+            .count()
+    }
+
+    /**
+     * Clears everything that was filled in by evaluating the individual. The
+     * lineage stays, it describes where the individual came from.
+     */
+    pub fn reset_evaluation(&mut self) {
+        self.fitness = 0.0;
+        self.objectives.clear();
+        self.behavior.clear();
+        self.novelty = None;
+        self.selection_score = None;
+    }
+
+    /**
+     * The `selection_score` if there is one, otherwise the `fitness`
+     */
+    pub fn score(&self) -> f32 {
+        self.selection_score.unwrap_or(self.fitness)
+    }
+
+    /**
+     * Forgets where the individual came from, so it no longer counts as
+     * offspring for the adaptive schedules or as a trial vector
+     */
+    pub fn clear_lineage(&mut self) {
+        self.parent_fitness = None;
+        self.trial_target = None;
+    }
+
+    /**
+     * All weights flattened into one vector: the input matrix, then each hidden
+     * matrix, then the output matrix, every matrix in column-major order
+     */
+    pub fn weights(&self) -> Vec<f32> {
+        self.input_matrix
+            .iter()
+            .chain(self.matricies.iter().flat_map(|matrix| matrix.iter()))
+            .chain(self.output_matrix.iter())
+            .copied()
+            .collect()
+    }
+
+    /**
+     * Inverse of `weights`, the slice must have exactly `WEIGHT_COUNT` elements
+     */
+    pub fn set_weights(&mut self, weights: &[f32]) {
+        assert_eq!(weights.len(), Self::WEIGHT_COUNT);
+
+        for (weight, value) in self
+            .input_matrix
+            .iter_mut()
+            .chain(
+                self.matricies
+                    .iter_mut()
+                    .flat_map(|matrix| matrix.iter_mut()),
+            )
+            .chain(self.output_matrix.iter_mut())
+            .zip(weights)
+        {
+            *weight = *value;
+        }
+    }
+
+    /**
+     * Copies of the input matrix, each hidden matrix and the output matrix, in
+     * evaluation order
+     */
+    pub fn layers(&self) -> Vec<nalgebra::DMatrix<f32>> {
+        let mut layers = vec![nalgebra::DMatrix::from_column_slice(
+            INPUTS,
+            SUBLAYERS,
+            self.input_matrix.as_slice(),
+        )];
+        layers.extend(self.matricies.iter().map(|matrix| {
+            nalgebra::DMatrix::from_column_slice(SUBLAYERS, SUBLAYERS, matrix.as_slice())
+        }));
+        layers.push(nalgebra::DMatrix::from_column_slice(
+            SUBLAYERS,
+            OUTPUTS,
+            self.output_matrix.as_slice(),
+        ));
+        layers
+    }
+
+    /**
+     * Inverse of `layers`, the matrices must have the same shapes
+     */
+    pub fn set_layers(&mut self, layers: &[nalgebra::DMatrix<f32>]) {
+        assert_eq!(layers.len(), LAYERS + 2);
+        let weights: Vec<f32> = layers
+            .iter()
+            .flat_map(|layer| layer.iter().copied())
+            .collect();
+        self.set_weights(&weights);
+    }
+
     pub fn mutate<RAND: rand::Rng>(&mut self, rng: &mut RAND) -> () {
+        self.mutate_with_strength(0.1, rng);
+    }
+
+    /**
+     * Like `mutate`, shifting the weight by at most `strength`
+     */
+    pub fn mutate_with_strength<RAND: rand::Rng>(&mut self, strength: f32, rng: &mut RAND) {
         let layer = rng.gen_range(0..LAYERS + 2);
         if layer == 0 {
-            mutate_matrix(&mut self.input_matrix, rng);
+            mutate_matrix(&mut self.input_matrix, strength, rng);
         } else if layer == LAYERS + 1 {
-            mutate_matrix(&mut self.output_matrix, rng);
+            mutate_matrix(&mut self.output_matrix, strength, rng);
         } else {
-            mutate_matrix(&mut self.matricies[layer - 1], rng);
+            mutate_matrix(&mut self.matricies[layer - 1], strength, rng);
         }
     }
 
     pub fn new_random<RAND: rand::Rng>(rng: &mut RAND) -> Self {
+        Self::new_initialized(&Initializer::default(), rng)
+    }
+
+    pub fn new_initialized<RAND: rand::Rng>(initializer: &Initializer, rng: &mut RAND) -> Self {
         let mut matricies = [EvolutionMatrix::<SUBLAYERS, SUBLAYERS>::zeros(); LAYERS];
 
         for i in matricies.iter_mut() {
-            *i = random_matrix(rng);
+            *i = initializer.matrix(rng);
         }
 
         return Self {
-            input_matrix: random_matrix(rng),
-            output_matrix: random_matrix(rng),
+            input_matrix: initializer.matrix(rng),
+            output_matrix: initializer.matrix(rng),
             matricies,
             fitness: 0.0,
+            objectives: Vec::new(),
+            behavior: Vec::new(),
+            step_sizes: Vec::new(),
+            parent_fitness: None,
+            trial_target: None,
+            novelty: None,
+            selection_score: None,
         };
     }
 }
 
-#[derive(Clone, PartialEq, Debug, Serialize)]
+/**
+ * A configuration change `Population::schedule` applies at a later generation
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum ConfigChange {
+    MaxSize(usize),
+    MaxSpecies(usize),
+    MaxMutations(usize),
+    MutationCount(Schedule),
+    MutationStrength(Schedule),
+    SpeciationThreshold(f32),
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Population<
     const INPUTS: usize,
     const LAYERS: usize,
@@ -134,19 +346,60 @@ pub struct Population<
     const SUBLAYERS: usize,
 > {
     pub individuals: Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    /**
+     * Used for every random individual the population creates after construction
+     */
+    pub initializer: Initializer,
     max_size: usize,
     max_species: usize,
+    /**
+     * Upper bound of the number of mutations every offspring receives
+     */
+    pub mutation_count: Schedule,
+    /**
+     * Largest change a single mutation makes to a weight
+     */
+    pub mutation_strength: Schedule,
+    pub mutation_mode: MutationMode,
+    scheduled_changes: Vec<(usize, ConfigChange)>,
+    parents: Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    pub novelty_archive: NoveltyArchive,
+    pub hall_of_fame: HallOfFame<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    pub diversity_control: Option<DiversityControl>,
+    pub distance_metric: DistanceMetric,
+    /**
+     * Individuals closer than this to a species' champion join that species
+     */
+    pub speciation_threshold: f32,
+    pub speciation: Speciation,
+    pub probes: ProbeSet<INPUTS>,
+    generation: usize,
+    history: History,
+    #[serde(skip)]
+    observers: Observers<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
 }
 
 impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
     Population<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
 {
     pub fn new<RNG: rand::Rng>(max_size: usize, max_species: usize, rng: &mut RNG) -> Self {
-        Self {
-            max_size,
+        Self::with_initializer(max_size, max_species, Initializer::default(), rng)
+    }
+
+    pub fn with_initializer<RNG: rand::Rng>(
+        max_size: usize,
+        max_species: usize,
+        initializer: Initializer,
+        rng: &mut RNG,
+    ) -> Self {
+        let mut population = Self::new_from_individuals(
             max_species,
-            individuals: (0..max_size).map(|_| Individual::new_random(rng)).collect(),
-        }
+            (0..max_size)
+                .map(|_| Individual::new_initialized(&initializer, rng))
+                .collect(),
+        );
+        population.initializer = initializer;
+        population
     }
 
     pub fn new_from_individuals(
@@ -155,57 +408,369 @@ impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLA
     ) -> Self {
         Self {
             max_size: individuals.len(),
-            max_species,
+            max_species: max_species.max(1),
+            mutation_count: Schedule::Constant(19.0),
+            mutation_strength: Schedule::Constant(0.1),
+            mutation_mode: MutationMode::Global,
+            scheduled_changes: Vec::new(),
             individuals,
+            initializer: Initializer::default(),
+            parents: Vec::new(),
+            novelty_archive: NoveltyArchive::default(),
+            hall_of_fame: HallOfFame::default(),
+            diversity_control: None,
+            distance_metric: DistanceMetric::default(),
+            speciation_threshold: 0.5,
+            speciation: Speciation::default(),
+            probes: ProbeSet::default(),
+            generation: 0,
+            history: History::default(),
+            observers: Observers::default(),
+        }
+    }
+
+    /**
+     * A population of `max_size` made of the seeds, a `random_fraction` of new
+     * random individuals and mutated copies of the seeds for the rest
+     */
+    pub fn from_seeds<RNG: rand::Rng>(
+        seeds: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+        max_size: usize,
+        max_species: usize,
+        random_fraction: f32,
+        rng: &mut RNG,
+    ) -> Self {
+        let mut population = Self::new_from_individuals(max_species, Vec::new());
+        population.max_size = max_size;
+        population.reseed(seeds, random_fraction, rng);
+        population
+    }
+
+    /**
+     * Replaces the individuals like `from_seeds`, keeping the configuration
+     */
+    pub fn reseed<RNG: rand::Rng>(
+        &mut self,
+        seeds: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+        random_fraction: f32,
+        rng: &mut RNG,
+    ) {
+        let mut individuals: Vec<_> = seeds.iter().take(self.max_size).cloned().collect();
+        for individual in individuals.iter_mut() {
+            individual.reset_evaluation();
+            individual.clear_lineage();
         }
+
+        let remaining = self.max_size - individuals.len();
+        let random = if seeds.is_empty() {
+            remaining
+        } else {
+            ((self.max_size as f32 * random_fraction).round() as usize).min(remaining)
+        };
+
+        for i in 0..remaining - random {
+            individuals.push(self.offspring(&seeds[i % seeds.len()], rng));
+        }
+        for _ in 0..random {
+            individuals.push(Individual::new_initialized(&self.initializer, rng));
+        }
+        self.individuals = individuals;
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /**
+     * Takes effect the next time the population evolves
+     */
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+    }
+
+    pub fn max_species(&self) -> usize {
+        self.max_species
     }
 
     /**
-     * Assumes the list of species is sorted by fitness
+     * At least one species is always kept, so 0 is treated as 1
      */
-    fn speciate(&self) -> Vec<Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>> {
-        let mut species = vec![vec![self.individuals[0].clone()]];
-        for individual in &self.individuals[1..] {
-            match species
-                .iter_mut()
-                .find(|k| k[0].similarity(individual) < 0.5)
+    pub fn set_max_species(&mut self, max_species: usize) {
+        self.max_species = max_species.max(1);
+    }
+
+    /**
+     * Every offspring receives between 1 and this many mutations, the current
+     * value of `mutation_count`
+     */
+    pub fn max_mutations(&self) -> usize {
+        (self.mutation_count.value(self.generation).round() as usize).max(1)
+    }
+
+    pub fn set_max_mutations(&mut self, max_mutations: usize) {
+        self.mutation_count = Schedule::Constant(max_mutations as f32);
+    }
+
+    /**
+     * Applies `change` when generation `generation` evolves, so the generation
+     * after it is the first one created with the new configuration
+     */
+    pub fn schedule(&mut self, generation: usize, change: ConfigChange) {
+        self.scheduled_changes.push((generation, change));
+    }
+
+    pub fn scheduled_changes(&self) -> &[(usize, ConfigChange)] {
+        &self.scheduled_changes
+    }
+
+    pub fn apply(&mut self, change: ConfigChange) {
+        match change {
+            ConfigChange::MaxSize(max_size) => self.max_size = max_size,
+            ConfigChange::MaxSpecies(max_species) => self.set_max_species(max_species),
+            ConfigChange::MaxMutations(max_mutations) => self.set_max_mutations(max_mutations),
+            ConfigChange::MutationCount(schedule) => self.mutation_count = schedule,
+            ConfigChange::MutationStrength(schedule) => self.mutation_strength = schedule,
+            ConfigChange::SpeciationThreshold(threshold) => self.speciation_threshold = threshold,
+        }
+    }
+
+    fn apply_scheduled_changes(&mut self) {
+        let generation = self.generation;
+        let (due, pending) = std::mem::take(&mut self.scheduled_changes)
+            .into_iter()
+            .partition(|(at, _)| *at <= generation);
+        self.scheduled_changes = pending;
+
+        let mut due: Vec<(usize, ConfigChange)> = due;
+        due.sort_by_key(|(at, _)| *at);
+        for (_, change) in due {
+            self.apply(change);
+        }
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /**
+     * Statistics of every generation evolved so far
+     */
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /**
+     * Registers an observer, starting with the current generation that is
+     * waiting to be evaluated. Wrap it in an `Rc<RefCell<_>>` and keep a clone
+     * to read its state back later.
+     */
+    pub fn add_observer(
+        &mut self,
+        mut observer: impl EvolutionObserver<INPUTS, LAYERS, OUTPUTS, SUBLAYERS> + 'static,
+    ) {
+        observer.on_generation_start(self.generation);
+        self.observers.0.push(Box::new(observer));
+    }
+
+    pub fn clear_observers(&mut self) {
+        self.observers.0.clear();
+    }
+
+    fn record_statistics(
+        &mut self,
+        species: &[Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>],
+    ) {
+        for observer in self.observers.0.iter_mut() {
+            observer.on_evaluated(self.generation, &self.individuals);
+            observer.on_species_formed(self.generation, species);
+        }
+
+        let statistics = GenerationStatistics::new(
+            self.generation,
+            &self.individuals,
+            species,
+            self.distance_metric,
+        );
+        let improved = self
+            .history
+            .best_generation()
+            .map_or(true, |best| statistics.best_fitness > best.best_fitness);
+        self.history.push(statistics);
+        self.hall_of_fame.update(&self.individuals);
+
+        if improved {
+            if let Some(champion) = self
+                .individuals
+                .iter()
+                .max_by(|i, j| i.fitness.total_cmp(&j.fitness))
             {
-                Some(specie) => specie.push(individual.clone()),
-                None => species.push(vec![individual.clone()]),
+                for observer in self.observers.0.iter_mut() {
+                    observer.on_champion_improved(self.generation, champion);
+                }
+            }
+        }
+    }
+
+    fn finish_generation(&mut self) {
+        if let Some(statistics) = self.history.latest() {
+            for observer in self.observers.0.iter_mut() {
+                observer.on_generation_end(statistics);
+            }
+        }
+        self.probes.start_generation();
+        self.generation += 1;
+
+        for observer in self.observers.0.iter_mut() {
+            observer.on_generation_start(self.generation);
+        }
+    }
+
+    /**
+     * Offers evaluation inputs to the probe set used by behavioral speciation
+     */
+    pub fn record_probe<RNG: rand::Rng>(&mut self, inputs: [f32; INPUTS], rng: &mut RNG) {
+        self.probes.record(inputs, rng);
+    }
+
+    /**
+     * Records statistics for strategies that keep the individuals in their own
+     * order and do not speciate themselves
+     */
+    fn record_generation(&mut self) {
+        let mut individuals = self.individuals.clone();
+        individuals.sort_by(|i, j| (-i.fitness).total_cmp(&-j.fitness));
+        let species = self.speciate(&individuals);
+        self.record_statistics(&species);
+    }
+
+    /**
+     * The evaluated parents kept between generations by strategies that only
+     * replace a parent once its offspring has been evaluated
+     */
+    pub fn parents(&self) -> &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>] {
+        &self.parents
+    }
+
+    fn offspring<RNG: rand::Rng>(
+        &self,
+        parent: &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        rng: &mut RNG,
+    ) -> Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS> {
+        let mut child = parent.clone();
+        let strength = self.mutation_strength.value(self.generation);
+        let mutations = rng.gen_range(1..=self.max_mutations());
+        match self.mutation_mode {
+            MutationMode::Global => {
+                for _ in 0..mutations {
+                    child.mutate_with_strength(strength, rng);
+                }
+            }
+            MutationMode::SelfAdaptive {
+                learning_rate,
+                min_step,
+            } => child.mutate_self_adaptive(mutations, strength, learning_rate, min_step, rng),
+        }
+        child.reset_evaluation();
+        child.clear_lineage();
+        child.parent_fitness = Some(parent.fitness);
+        child
+    }
+
+    /**
+     * Feeds the share of evaluated offspring that beat their parent to the
+     * adaptive schedules
+     */
+    fn adapt_schedules(&mut self) {
+        let (successes, offspring) = self
+            .individuals
+            .iter()
+            .filter_map(|child| child.parent_fitness.map(|parent| child.fitness > parent))
+            .fold((0, 0), |(successes, offspring), success| {
+                (successes + success as usize, offspring + 1)
+            });
+        if offspring > 0 {
+            let success_rate = successes as f32 / offspring as f32;
+            self.mutation_count.adapt(success_rate);
+            self.mutation_strength.adapt(success_rate);
+        }
+    }
+
+    /**
+     * Assumes the list of individuals is sorted best first
+     */
+    fn speciate(
+        &self,
+        individuals: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+    ) -> Vec<Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>> {
+        let signatures: Vec<Vec<f32>> = match self.speciation {
+            Speciation::Behavior { .. } if !self.probes.is_empty() => individuals
+                .iter()
+                .map(|i| i.probe_outputs(&self.probes))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let same_species = |one: usize, two: usize| match self.speciation {
+            Speciation::Behavior { threshold } if !signatures.is_empty() => {
+                output_distance(&signatures[one], &signatures[two]) < threshold
+            }
+            _ => {
+                individuals[one].distance(&individuals[two], self.distance_metric)
+                    < self.speciation_threshold
+            }
+        };
+
+        let mut species: Vec<Vec<usize>> = Vec::new();
+        for index in 0..individuals.len() {
+            match species.iter_mut().find(|k| same_species(k[0], index)) {
+                Some(specie) => specie.push(index),
+                None => species.push(vec![index]),
             }
         }
         species
+            .into_iter()
+            .map(|specie| specie.into_iter().map(|i| individuals[i].clone()).collect())
+            .collect()
     }
 
     pub fn evolve<RNG: rand::Rng>(&mut self, rng: &mut RNG) {
+        self.apply_scheduled_changes();
+        self.adapt_schedules();
         self.individuals
-            .sort_by(|i, j| (-i.fitness).total_cmp(&-j.fitness));
+            .sort_by(|i, j| (-i.score()).total_cmp(&-j.score()));
 
-        let species = self.speciate();
+        let species = self.speciate(&self.individuals);
+        self.record_statistics(&species);
         let mut new_individuals: Vec<_> = species
             .iter()
-            .take(self.max_species)
-            .map(|i| i[0].clone())
+            .take(self.max_species.min(self.max_size))
+            .map(|i| {
+                let mut elite = i[0].clone();
+                elite.reset_evaluation();
+                elite.clear_lineage();
+                elite
+            })
             .collect();
+        let elites = new_individuals.len();
 
+        // An empty population has no species to breed from
+        let breeding_species = species.len().min(self.max_species);
         let mut i = 0;
         while new_individuals.len() < self.max_size {
-            let specie = &species[i % species.len().min(self.max_species)];
-            let mut new_individual = specie[rng
-                .gen_range(0..specie.len())
-                .min(rng.gen_range(0..specie.len()))]
-            .clone();
-
-            for _ in 0..rng.gen_range(1..20) {
-                new_individual.mutate(rng);
+            if breeding_species == 0 {
+                new_individuals.push(Individual::new_initialized(&self.initializer, rng));
+                continue;
             }
-            new_individuals.push(new_individual);
+
+            let specie = &species[i % breeding_species];
+            let parent = &specie[rng
+                .gen_range(0..specie.len())
+                .min(rng.gen_range(0..specie.len()))];
+            new_individuals.push(self.offspring(parent, rng));
             i += 1;
         }
+        self.control_diversity(&mut new_individuals, elites, rng);
 
-        for individual in new_individuals.iter_mut() {
-            individual.fitness = 0.0;
-        }
         self.individuals = new_individuals;
+        self.finish_generation();
     }
 }
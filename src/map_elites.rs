@@ -0,0 +1,220 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::Individual;
+
+/**
+ * One axis of the MAP-Elites grid, an entry of `Individual::behavior`
+ * divided into `bins` equal cells between `min` and `max`
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedDimension")]
+pub struct BehaviorDimension {
+    min: f32,
+    max: f32,
+    bins: usize,
+}
+
+#[derive(Deserialize)]
+struct UncheckedDimension {
+    min: f32,
+    max: f32,
+    bins: usize,
+}
+
+impl TryFrom<UncheckedDimension> for BehaviorDimension {
+    type Error = &'static str;
+
+    fn try_from(dimension: UncheckedDimension) -> Result<Self, Self::Error> {
+        if dimension.bins > 0 && dimension.max > dimension.min {
+            Ok(Self {
+                min: dimension.min,
+                max: dimension.max,
+                bins: dimension.bins,
+            })
+        } else {
+            Err("A behavior dimension needs at least one bin and max above min")
+        }
+    }
+}
+
+impl BehaviorDimension {
+    pub fn new(min: f32, max: f32, bins: usize) -> Self {
+        assert!(
+            bins > 0 && max > min,
+            "A behavior dimension needs at least one bin and max above min"
+        );
+        Self { min, max, bins }
+    }
+
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    pub fn bins(&self) -> usize {
+        self.bins
+    }
+
+    /**
+     * Values outside of the range end up in the first or last bin
+     */
+    pub fn bin(&self, value: f32) -> usize {
+        let position = (value - self.min) / (self.max - self.min);
+        ((position * self.bins as f32).max(0.0) as usize).min(self.bins - 1)
+    }
+}
+
+/**
+ * Grid archive holding the fittest individual found for every combination of
+ * behavior bins
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MapElites<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+> {
+    dimensions: Vec<BehaviorDimension>,
+    cells: Vec<Option<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>>,
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    MapElites<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    pub fn new(dimensions: Vec<BehaviorDimension>) -> Self {
+        let size = dimensions.iter().map(|i| i.bins).product();
+        Self {
+            dimensions,
+            cells: vec![None; size],
+        }
+    }
+
+    pub fn dimensions(&self) -> &[BehaviorDimension] {
+        &self.dimensions
+    }
+
+    /**
+     * Index of the cell a behavior belongs to, `None` if the behavior does
+     * not have one value per dimension
+     */
+    pub fn cell_index(&self, behavior: &[f32]) -> Option<usize> {
+        if behavior.len() != self.dimensions.len() {
+            return None;
+        }
+
+        Some(
+            self.dimensions
+                .iter()
+                .zip(behavior)
+                .fold(0, |index, (dimension, value)| {
+                    index * dimension.bins + dimension.bin(*value)
+                }),
+        )
+    }
+
+    /**
+     * Looks up a cell by its bin in every dimension
+     */
+    pub fn get(&self, bins: &[usize]) -> Option<&Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        if bins.len() != self.dimensions.len()
+            || bins
+                .iter()
+                .zip(&self.dimensions)
+                .any(|(bin, i)| *bin >= i.bins)
+        {
+            return None;
+        }
+
+        let index = bins
+            .iter()
+            .zip(&self.dimensions)
+            .fold(0, |index, (bin, dimension)| index * dimension.bins + bin);
+        self.cells[index].as_ref()
+    }
+
+    /**
+     * Stores the individual if its cell is empty or holds a less fit elite.
+     * Returns whether the individual was stored.
+     */
+    pub fn insert(&mut self, individual: Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>) -> bool {
+        let Some(index) = self.cell_index(&individual.behavior) else {
+            return false;
+        };
+
+        match &self.cells[index] {
+            Some(elite) if elite.fitness >= individual.fitness => false,
+            _ => {
+                self.cells[index] = Some(individual);
+                true
+            }
+        }
+    }
+
+    /**
+     * Inserts every individual, returns how many were stored
+     */
+    pub fn insert_all(
+        &mut self,
+        individuals: impl IntoIterator<Item = Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    ) -> usize {
+        individuals
+            .into_iter()
+            .map(|individual| self.insert(individual))
+            .filter(|stored| *stored)
+            .count()
+    }
+
+    pub fn elites(&self) -> impl Iterator<Item = &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        self.cells.iter().flatten()
+    }
+
+    pub fn best(&self) -> Option<&Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        self.elites().max_by(|i, j| i.fitness.total_cmp(&j.fitness))
+    }
+
+    /**
+     * Fraction of the cells that hold an elite
+     */
+    pub fn coverage(&self) -> f32 {
+        self.elites().count() as f32 / self.cells.len() as f32
+    }
+
+    /**
+     * Mutated copy of the elite of a random filled cell, `None` while the
+     * archive is empty
+     */
+    pub fn offspring<RNG: Rng>(
+        &self,
+        rng: &mut RNG,
+    ) -> Option<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        let filled: Vec<_> = self.elites().collect();
+        if filled.is_empty() {
+            return None;
+        }
+
+        let mut child = filled[rng.gen_range(0..filled.len())].clone();
+        for _ in 0..rng.gen_range(1..20) {
+            child.mutate(rng);
+        }
+        child.reset_evaluation();
+        child.clear_lineage();
+        Some(child)
+    }
+
+    /**
+     * A batch of offspring to evaluate, for instance with
+     * `Population::new_from_individuals`
+     */
+    pub fn offspring_batch<RNG: Rng>(
+        &self,
+        count: usize,
+        rng: &mut RNG,
+    ) -> Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        (0..count).filter_map(|_| self.offspring(rng)).collect()
+    }
+}
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Population;
+
+fn behavior_distance(one: &[f32], two: &[f32]) -> f32 {
+    one.iter()
+        .zip(two)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f32>()
+        .sqrt()
+}
+
+/**
+ * Behaviors of past individuals that novelty is measured against
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct NoveltyArchive {
+    pub behaviors: Vec<Vec<f32>>,
+    /// Number of nearest neighbors averaged to compute novelty
+    pub neighbors: usize,
+    /// How many of the most novel individuals are archived every generation
+    pub additions: usize,
+    /// Once full the oldest behaviors are dropped
+    pub capacity: usize,
+}
+
+impl Default for NoveltyArchive {
+    fn default() -> Self {
+        Self::new(15, 1, 1000)
+    }
+}
+
+impl NoveltyArchive {
+    pub fn new(neighbors: usize, additions: usize, capacity: usize) -> Self {
+        Self {
+            behaviors: Vec::new(),
+            neighbors,
+            additions,
+            capacity,
+        }
+    }
+
+    /**
+     * Mean distance from `behavior` to its nearest neighbors among `others`
+     * and the archive
+     */
+    pub fn novelty<'a>(
+        &self,
+        behavior: &[f32],
+        others: impl IntoIterator<Item = &'a [f32]>,
+    ) -> f32 {
+        let mut distances: Vec<f32> = others
+            .into_iter()
+            .map(|other| behavior_distance(behavior, other))
+            .collect();
+        distances.extend(
+            self.behaviors
+                .iter()
+                .map(|other| behavior_distance(behavior, other)),
+        );
+        if distances.is_empty() {
+            return 0.0;
+        }
+
+        distances.sort_by(f32::total_cmp);
+        distances.truncate(self.neighbors.max(1));
+        distances.iter().sum::<f32>() / distances.len() as f32
+    }
+
+    pub fn add(&mut self, behavior: Vec<f32>) {
+        self.behaviors.push(behavior);
+        if self.behaviors.len() > self.capacity {
+            let excess = self.behaviors.len() - self.capacity;
+            self.behaviors.drain(..excess);
+        }
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Population<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Scores every individual by the novelty of its `behavior` and blends it
+     * with the fitness into the `selection_score` that `evolve` selects on,
+     * `blend` of 0 keeps the fitness and 1 replaces it with the novelty. The
+     * `fitness` itself is left alone, so statistics and the hall of fame still
+     * see the task score. The most novel behaviors are then added to the
+     * archive.
+     *
+     * Call after evaluation and before `evolve`. Returns the novelty scores.
+     */
+    pub fn apply_novelty(&mut self, blend: f32) -> Vec<f32> {
+        let novelty: Vec<f32> = self
+            .individuals
+            .iter()
+            .enumerate()
+            .map(|(index, individual)| {
+                self.novelty_archive.novelty(
+                    &individual.behavior,
+                    self.individuals
+                        .iter()
+                        .enumerate()
+                        .filter(|(other, _)| *other != index)
+                        .map(|(_, other)| other.behavior.as_slice()),
+                )
+            })
+            .collect();
+
+        for (individual, novelty) in self.individuals.iter_mut().zip(&novelty) {
+            individual.novelty = Some(*novelty);
+            individual.selection_score = Some((1.0 - blend) * individual.fitness + blend * novelty);
+        }
+
+        let mut order: Vec<usize> = (0..novelty.len()).collect();
+        order.sort_by(|&i, &j| novelty[j].total_cmp(&novelty[i]));
+        for &index in order.iter().take(self.novelty_archive.additions) {
+            let behavior = self.individuals[index].behavior.clone();
+            self.novelty_archive.add(behavior);
+        }
+
+        novelty
+    }
+}
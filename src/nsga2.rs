@@ -0,0 +1,163 @@
+use rand::Rng;
+
+use crate::{Individual, Population};
+
+/**
+ * Whether `one` is at least as good as `two` in every objective and strictly
+ * better in at least one. All objectives are maximised.
+ */
+pub fn dominates(one: &[f32], two: &[f32]) -> bool {
+    one.iter().zip(two).all(|(a, b)| a >= b) && one.iter().zip(two).any(|(a, b)| a > b)
+}
+
+/**
+ * Fast non-dominated sort. Returns the fronts as indices into `objectives`,
+ * starting with the Pareto front.
+ */
+pub fn non_dominated_sort(objectives: &[Vec<f32>]) -> Vec<Vec<usize>> {
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); objectives.len()];
+    let mut domination_count = vec![0usize; objectives.len()];
+
+    for i in 0..objectives.len() {
+        for j in 0..objectives.len() {
+            if dominates(&objectives[i], &objectives[j]) {
+                dominated[i].push(j);
+            } else if dominates(&objectives[j], &objectives[i]) {
+                domination_count[i] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut front: Vec<usize> = (0..objectives.len())
+        .filter(|&i| domination_count[i] == 0)
+        .collect();
+    while !front.is_empty() {
+        let mut next = Vec::new();
+        for &i in &front {
+            for &j in &dominated[i] {
+                domination_count[j] -= 1;
+                if domination_count[j] == 0 {
+                    next.push(j);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next;
+    }
+    fronts
+}
+
+/**
+ * Crowding distance of every member of `front`, in the same order as `front`.
+ * The boundary solutions of each objective get an infinite distance.
+ */
+pub fn crowding_distance(objectives: &[Vec<f32>], front: &[usize]) -> Vec<f32> {
+    let mut distance = vec![0.0; front.len()];
+    let Some(&first) = front.first() else {
+        return distance;
+    };
+
+    for objective in 0..objectives[first].len() {
+        let values: Vec<f32> = front
+            .iter()
+            .map(|&i| objectives[i].get(objective).copied().unwrap_or(0.0))
+            .collect();
+
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+        let last = order.len() - 1;
+        let range = values[order[last]] - values[order[0]];
+        distance[order[0]] = f32::INFINITY;
+        distance[order[last]] = f32::INFINITY;
+
+        if range > 0.0 {
+            for k in 1..last {
+                distance[order[k]] += (values[order[k + 1]] - values[order[k - 1]]) / range;
+            }
+        }
+    }
+    distance
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Population<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * The non-dominated individuals of the current, evaluated, population
+     */
+    pub fn pareto_front(&self) -> Vec<&Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        let objectives: Vec<_> = self
+            .individuals
+            .iter()
+            .map(|i| i.objectives.clone())
+            .collect();
+
+        match non_dominated_sort(&objectives).first() {
+            Some(front) => front.iter().map(|&i| &self.individuals[i]).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /**
+     * NSGA-II step, an alternative to `evolve` that selects on `objectives`
+     * instead of `fitness`.
+     *
+     * The evaluated individuals are merged with the parents of the previous
+     * generation, the best `max_size` by rank and crowding distance become the
+     * new parents and the offspring are picked from them by binary tournament.
+     * Adaptive mutation schedules judge offspring by `fitness`, so set it too
+     * when using them.
+     */
+    pub fn evolve_nsga2<RNG: Rng>(&mut self, rng: &mut RNG) {
+        self.apply_scheduled_changes();
+        self.adapt_schedules();
+        self.record_generation();
+
+        let mut candidates = std::mem::take(&mut self.parents);
+        candidates.append(&mut self.individuals);
+        let objectives: Vec<_> = candidates.iter().map(|i| i.objectives.clone()).collect();
+
+        // (index into candidates, rank, crowding distance)
+        let mut selected: Vec<(usize, usize, f32)> = Vec::with_capacity(self.max_size);
+        for (rank, front) in non_dominated_sort(&objectives).into_iter().enumerate() {
+            if selected.len() >= self.max_size {
+                break;
+            }
+
+            let distance = crowding_distance(&objectives, &front);
+            let mut members: Vec<_> = front
+                .into_iter()
+                .zip(distance)
+                .map(|(index, distance)| (index, rank, distance))
+                .collect();
+            members.sort_by(|a, b| b.2.total_cmp(&a.2));
+            members.truncate(self.max_size - selected.len());
+            selected.extend(members);
+        }
+
+        if selected.is_empty() {
+            self.finish_generation();
+            return;
+        }
+
+        self.individuals = (0..self.max_size)
+            .map(|_| {
+                let first = selected[rng.gen_range(0..selected.len())];
+                let second = selected[rng.gen_range(0..selected.len())];
+                let winner = if first.1 < second.1 || (first.1 == second.1 && first.2 > second.2) {
+                    first
+                } else {
+                    second
+                };
+                self.offspring(&candidates[winner.0], rng)
+            })
+            .collect();
+        self.parents = selected
+            .into_iter()
+            .map(|(index, _, _)| candidates[index].clone())
+            .collect();
+        self.finish_generation();
+    }
+}
@@ -0,0 +1,254 @@
+use std::{cell::RefCell, io::Write, rc::Rc};
+
+use serde::Serialize;
+
+use crate::{statistics::GenerationStatistics, Individual};
+
+/**
+ * Callbacks invoked by `Population` while it evolves, every method does
+ * nothing by default
+ */
+pub trait EvolutionObserver<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+>
+{
+    /**
+     * Called once a generation has been created, before it is evaluated. The
+     * generation waiting for evaluation when the observer is added counts too.
+     */
+    fn on_generation_start(&mut self, _generation: usize) {}
+
+    /**
+     * Called with the evaluated individuals, before any of them are replaced
+     */
+    fn on_evaluated(
+        &mut self,
+        _generation: usize,
+        _individuals: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+    ) {
+    }
+
+    fn on_species_formed(
+        &mut self,
+        _generation: usize,
+        _species: &[Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>],
+    ) {
+    }
+
+    /**
+     * Called when a generation reaches a higher best fitness than any generation
+     * before it
+     */
+    fn on_champion_improved(
+        &mut self,
+        _generation: usize,
+        _champion: &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    ) {
+    }
+
+    /**
+     * Called once the next generation has been created
+     */
+    fn on_generation_end(&mut self, _statistics: &GenerationStatistics) {}
+}
+
+/**
+ * Shares an observer with the population, so it can still be inspected after
+ * `Population::add_observer` takes ownership of the handle
+ */
+impl<
+        T: EvolutionObserver<INPUTS, LAYERS, OUTPUTS, SUBLAYERS> + ?Sized,
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    > EvolutionObserver<INPUTS, LAYERS, OUTPUTS, SUBLAYERS> for Rc<RefCell<T>>
+{
+    fn on_generation_start(&mut self, generation: usize) {
+        self.borrow_mut().on_generation_start(generation);
+    }
+
+    fn on_evaluated(
+        &mut self,
+        generation: usize,
+        individuals: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+    ) {
+        self.borrow_mut().on_evaluated(generation, individuals);
+    }
+
+    fn on_species_formed(
+        &mut self,
+        generation: usize,
+        species: &[Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>],
+    ) {
+        self.borrow_mut().on_species_formed(generation, species);
+    }
+
+    fn on_champion_improved(
+        &mut self,
+        generation: usize,
+        champion: &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    ) {
+        self.borrow_mut().on_champion_improved(generation, champion);
+    }
+
+    fn on_generation_end(&mut self, statistics: &GenerationStatistics) {
+        self.borrow_mut().on_generation_end(statistics);
+    }
+}
+
+/**
+ * Observers registered on a population. They are not part of its value: a
+ * clone starts without observers and comparisons ignore them.
+ */
+pub(crate) struct Observers<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+>(pub(crate) Vec<Box<dyn EvolutionObserver<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>>);
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize> Default
+    for Observers<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize> Clone
+    for Observers<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    PartialEq for Observers<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    std::fmt::Debug for Observers<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "{} observers", self.0.len())
+    }
+}
+
+/**
+ * Prints a line per generation and every time the champion improves
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct StdoutObserver;
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    EvolutionObserver<INPUTS, LAYERS, OUTPUTS, SUBLAYERS> for StdoutObserver
+{
+    fn on_champion_improved(
+        &mut self,
+        generation: usize,
+        champion: &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    ) {
+        println!(
+            "Generation {generation} new champion with fitness {:?}",
+            champion.fitness
+        );
+    }
+
+    fn on_generation_end(&mut self, statistics: &GenerationStatistics) {
+        println!(
+            "Generation {} best fitness: {:?}, mean fitness: {:?}, species: {}",
+            statistics.generation,
+            statistics.best_fitness,
+            statistics.mean_fitness,
+            statistics.species_count
+        );
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event<'a> {
+    ChampionImproved {
+        generation: usize,
+        fitness: f32,
+    },
+    GenerationEnd {
+        #[serde(flatten)]
+        statistics: &'a GenerationStatistics,
+    },
+}
+
+/**
+ * Writes one JSON object per line for every generation and champion
+ * improvement. Write errors stop the logging and can be retrieved with `error`,
+ * which needs the observer to be added as an `Rc<RefCell<_>>` that the caller
+ * keeps a clone of.
+ */
+pub struct JsonLinesObserver<W: Write> {
+    writer: W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: Write> JsonLinesObserver<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&std::io::Error> {
+        self.error.as_ref()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write(&mut self, event: &Event) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let result = serde_json::to_writer(&mut self.writer, event)
+            .map_err(std::io::Error::from)
+            .and_then(|_| writeln!(self.writer))
+            .and_then(|_| self.writer.flush());
+        if let Err(error) = result {
+            self.error = Some(error);
+        }
+    }
+}
+
+impl<
+        W: Write,
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    > EvolutionObserver<INPUTS, LAYERS, OUTPUTS, SUBLAYERS> for JsonLinesObserver<W>
+{
+    fn on_champion_improved(
+        &mut self,
+        generation: usize,
+        champion: &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    ) {
+        self.write(&Event::ChampionImproved {
+            generation,
+            fitness: champion.fitness,
+        });
+    }
+
+    fn on_generation_end(&mut self, statistics: &GenerationStatistics) {
+        self.write(&Event::GenerationEnd { statistics });
+    }
+}
@@ -0,0 +1,175 @@
+//! Export of individuals as ONNX models.
+//!
+//! The protobuf encoding is written by hand since the model only needs a
+//! handful of message types. Field numbers follow `onnx.proto`.
+
+use std::{fs, io, path::Path};
+
+use crate::Individual;
+
+const IR_VERSION: u64 = 8;
+const OPSET_VERSION: u64 = 13;
+const FLOAT: u64 = 1;
+const ATTRIBUTE_FLOAT: u64 = 1;
+
+/// Name of the graph input, shaped `[batch, INPUTS]`
+pub const INPUT_NAME: &str = "input";
+/// Name of the graph output, shaped `[batch, OUTPUTS]`
+pub const OUTPUT_NAME: &str = "output";
+
+#[derive(Default)]
+struct Message(Vec<u8>);
+
+impl Message {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.0.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+
+    fn key(&mut self, field: u64, wire_type: u64) {
+        self.varint(field << 3 | wire_type);
+    }
+
+    fn int(&mut self, field: u64, value: u64) -> &mut Self {
+        self.key(field, 0);
+        self.varint(value);
+        self
+    }
+
+    fn float(&mut self, field: u64, value: f32) -> &mut Self {
+        self.key(field, 5);
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn bytes(&mut self, field: u64, value: &[u8]) -> &mut Self {
+        self.key(field, 2);
+        self.varint(value.len() as u64);
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    fn string(&mut self, field: u64, value: &str) -> &mut Self {
+        self.bytes(field, value.as_bytes())
+    }
+
+    fn message(&mut self, field: u64, value: Message) -> &mut Self {
+        self.bytes(field, &value.0)
+    }
+}
+
+/**
+ * ValueInfoProto of a float tensor shaped `[batch, width]`
+ */
+fn value_info(name: &str, width: usize) -> Message {
+    let mut batch = Message::default();
+    batch.string(2, "batch");
+    let mut features = Message::default();
+    features.int(1, width as u64);
+    let mut shape = Message::default();
+    shape.message(1, batch).message(1, features);
+
+    let mut tensor = Message::default();
+    tensor.int(1, FLOAT).message(2, shape);
+    let mut value_type = Message::default();
+    value_type.message(1, tensor);
+
+    let mut info = Message::default();
+    info.string(1, name).message(2, value_type);
+    info
+}
+
+/**
+ * TensorProto holding a weight matrix in row-major order
+ */
+fn initializer(name: &str, matrix: &nalgebra::DMatrix<f32>) -> Message {
+    let mut data = Vec::with_capacity(matrix.len() * 4);
+    for row in matrix.row_iter() {
+        for weight in row.iter() {
+            data.extend_from_slice(&weight.to_le_bytes());
+        }
+    }
+
+    let mut tensor = Message::default();
+    tensor
+        .int(1, matrix.nrows() as u64)
+        .int(1, matrix.ncols() as u64)
+        .int(2, FLOAT)
+        .string(8, name)
+        .bytes(9, &data);
+    tensor
+}
+
+fn node(op_type: &str, inputs: &[&str], output: &str) -> Message {
+    let mut node = Message::default();
+    for input in inputs {
+        node.string(1, input);
+    }
+    node.string(2, output).string(3, output).string(4, op_type);
+    node
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Serialised ONNX model computing the same function as `evaluate` for a
+     * batch of inputs. Every layer is a MatMul followed by a LeakyRelu with
+     * alpha 0.5, and the output layer is a MatMul followed by Neg and
+     * Sigmoid since `evaluate` computes `1 / (1 + e^x)`.
+     */
+    pub fn to_onnx(&self) -> Vec<u8> {
+        let mut graph = Message::default();
+        graph.string(2, "evolution_rust");
+
+        let layers = self.layers();
+        let mut previous = INPUT_NAME.to_string();
+        for (index, layer) in layers.iter().enumerate() {
+            let weights = format!("weights_{}", index);
+            let product = format!("matmul_{}", index);
+            graph.message(5, initializer(&weights, layer));
+            graph.message(1, node("MatMul", &[&previous, &weights], &product));
+
+            if index + 1 < layers.len() {
+                let activation = format!("leaky_relu_{}", index);
+                let mut alpha = Message::default();
+                alpha
+                    .string(1, "alpha")
+                    .float(2, 0.5)
+                    .int(20, ATTRIBUTE_FLOAT);
+                let mut leaky_relu = node("LeakyRelu", &[&product], &activation);
+                leaky_relu.message(5, alpha);
+                graph.message(1, leaky_relu);
+                previous = activation;
+            } else {
+                graph.message(1, node("Neg", &[&product], "negated"));
+                graph.message(1, node("Sigmoid", &["negated"], OUTPUT_NAME));
+            }
+        }
+
+        graph.message(11, value_info(INPUT_NAME, INPUTS));
+        graph.message(12, value_info(OUTPUT_NAME, OUTPUTS));
+
+        let mut opset = Message::default();
+        opset.string(1, "").int(2, OPSET_VERSION);
+
+        let mut model = Message::default();
+        model
+            .int(1, IR_VERSION)
+            .string(2, "evolution_rust")
+            .string(3, env!("CARGO_PKG_VERSION"))
+            .message(7, graph)
+            .message(8, opset);
+        model.0
+    }
+
+    /**
+     * Writes `to_onnx` to a file
+     */
+    pub fn save_onnx<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_onnx())
+    }
+}
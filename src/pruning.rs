@@ -0,0 +1,148 @@
+use crate::Individual;
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct PruneReport {
+    /// Connections zeroed because their weight was below the threshold
+    pub pruned_connections: usize,
+    /// Connections zeroed because they belonged to a dead neuron
+    pub dead_connections: usize,
+    /// Hidden neurons without any incoming and outgoing connections afterwards
+    pub dead_neurons: usize,
+    /// Largest difference of any output on the probe inputs
+    pub max_deviation: f32,
+}
+
+impl PruneReport {
+    pub fn removed_connections(&self) -> usize {
+        self.pruned_connections + self.dead_connections
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Zeroes every non-zero weight smaller in magnitude than `threshold`.
+     * Returns how many were zeroed.
+     */
+    pub fn prune(&mut self, threshold: f32) -> usize {
+        let mut weights = self.weights();
+        let mut pruned = 0;
+        for weight in weights.iter_mut() {
+            if *weight != 0.0 && weight.abs() < threshold {
+                *weight = 0.0;
+                pruned += 1;
+            }
+        }
+        self.set_weights(&weights);
+        pruned
+    }
+
+    /**
+     * Zeroes the connections of hidden neurons that cannot affect the outputs:
+     * a neuron without incoming connections always outputs zero, so its
+     * outgoing connections are removed, and the incoming connections of a
+     * neuron without outgoing connections are removed. Repeats until nothing
+     * changes and returns how many connections were removed. The outputs stay
+     * exactly the same.
+     */
+    pub fn remove_dead_neurons(&mut self) -> usize {
+        let mut layers = self.layers();
+        let mut removed = 0;
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for hidden in 1..layers.len() {
+                for neuron in 0..SUBLAYERS {
+                    let incoming = layers[hidden - 1]
+                        .column(neuron)
+                        .iter()
+                        .filter(|weight| **weight != 0.0)
+                        .count();
+                    let outgoing = layers[hidden]
+                        .row(neuron)
+                        .iter()
+                        .filter(|weight| **weight != 0.0)
+                        .count();
+
+                    if incoming == 0 && outgoing > 0 {
+                        layers[hidden].row_mut(neuron).fill(0.0);
+                        removed += outgoing;
+                        changed = true;
+                    } else if outgoing == 0 && incoming > 0 {
+                        layers[hidden - 1].column_mut(neuron).fill(0.0);
+                        removed += incoming;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        self.set_layers(&layers);
+        removed
+    }
+
+    /**
+     * Number of hidden neurons without any incoming and outgoing connections
+     */
+    pub fn dead_neuron_count(&self) -> usize {
+        let layers = self.layers();
+        (1..layers.len())
+            .map(|hidden| {
+                (0..SUBLAYERS)
+                    .filter(|&neuron| {
+                        layers[hidden - 1].column(neuron).iter().all(|w| *w == 0.0)
+                            && layers[hidden].row(neuron).iter().all(|w| *w == 0.0)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /**
+     * Largest difference of any output between the two individuals on the
+     * probe inputs
+     */
+    pub fn max_output_deviation(&self, other: &Self, probes: &[[f32; INPUTS]]) -> f32 {
+        probes
+            .iter()
+            .flat_map(|inputs| {
+                self.evaluate(*inputs)
+                    .into_iter()
+                    .zip(other.evaluate(*inputs))
+                    .map(|(a, b)| (a - b).abs())
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /**
+     * Prunes small weights and removes dead neurons, then checks the outputs
+     * on the probe inputs. If any output moved by more than `tolerance` the
+     * individual is left unchanged and the report is returned as the error.
+     */
+    pub fn simplify(
+        &mut self,
+        threshold: f32,
+        probes: &[[f32; INPUTS]],
+        tolerance: f32,
+    ) -> Result<PruneReport, PruneReport> {
+        let original = self.clone();
+
+        let pruned_connections = self.prune(threshold);
+        let dead_connections = self.remove_dead_neurons();
+        let report = PruneReport {
+            pruned_connections,
+            dead_connections,
+            dead_neurons: self.dead_neuron_count(),
+            max_deviation: self.max_output_deviation(&original, probes),
+        };
+
+        if report.max_deviation > tolerance {
+            *self = original;
+            Err(report)
+        } else {
+            Ok(report)
+        }
+    }
+}
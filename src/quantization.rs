@@ -0,0 +1,228 @@
+//! Fixed-point inference for targets without floating point support.
+//!
+//! Activations are `i32` with `FRACTION_BITS` fractional bits. The weights of
+//! each layer are stored as `i8` or `i16` with a per-layer power of two
+//! scale, so rescaling the accumulator is a single rounding shift. The
+//! sigmoid is replaced by the piecewise linear PLAN approximation, which is
+//! within 0.02 of the real function.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Individual;
+
+pub const FRACTION_BITS: u32 = 16;
+/// 1.0 in fixed point
+pub const ONE: i32 = 1 << FRACTION_BITS;
+
+/// Largest weight shift, keeps tiny layers from needing huge shifts
+const MAX_SHIFT: u32 = 30;
+
+pub trait QuantizedWeight: Copy + Into<i64> {
+    const MAX: i64;
+
+    fn saturate(value: i64) -> Self;
+}
+
+impl QuantizedWeight for i8 {
+    const MAX: i64 = i8::MAX as i64;
+
+    fn saturate(value: i64) -> Self {
+        value.clamp(
+            -<Self as QuantizedWeight>::MAX,
+            <Self as QuantizedWeight>::MAX,
+        ) as i8
+    }
+}
+
+impl QuantizedWeight for i16 {
+    const MAX: i64 = i16::MAX as i64;
+
+    fn saturate(value: i64) -> Self {
+        value.clamp(
+            -<Self as QuantizedWeight>::MAX,
+            <Self as QuantizedWeight>::MAX,
+        ) as i16
+    }
+}
+
+pub fn to_fixed(value: f32) -> i32 {
+    (value * ONE as f32).round() as i32
+}
+
+pub fn from_fixed(value: i32) -> f32 {
+    value as f32 / ONE as f32
+}
+
+/**
+ * Integer version of the leaky relu used by `Individual::evaluate`
+ */
+pub fn leaky_relu(value: i32) -> i32 {
+    if value > 0 {
+        value
+    } else {
+        value / 2
+    }
+}
+
+/**
+ * PLAN approximation of `1 / (1 + e^value)`, matching the sigmoid used by
+ * `Individual::evaluate`
+ */
+pub fn sigmoid(value: i32) -> i32 {
+    let x = (value as i64).abs();
+    let one = ONE as i64;
+    let positive = if x >= 5 * one {
+        one
+    } else if x >= 19 * one / 8 {
+        (x >> 5) + 27 * one / 32
+    } else if x >= one {
+        (x >> 3) + 5 * one / 8
+    } else {
+        (x >> 2) + one / 2
+    };
+
+    // The sigmoid is mirrored, so a positive input gives the smaller half
+    if value > 0 {
+        (one - positive) as i32
+    } else {
+        positive as i32
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QuantizedLayer<W> {
+    pub inputs: usize,
+    pub outputs: usize,
+    /// The real weights are `weights * 2^-shift`
+    pub shift: u32,
+    /// Column-major like the nalgebra matrices, one column per output
+    pub weights: Vec<W>,
+}
+
+impl<W: QuantizedWeight> QuantizedLayer<W> {
+    fn new(matrix: &nalgebra::DMatrix<f32>) -> Self {
+        let max = matrix.amax();
+        let shift = if max == 0.0 {
+            0
+        } else {
+            (W::MAX as f32 / max)
+                .log2()
+                .floor()
+                .clamp(0.0, MAX_SHIFT as f32) as u32
+        };
+        let factor = (1u64 << shift) as f32;
+
+        QuantizedLayer {
+            inputs: matrix.nrows(),
+            outputs: matrix.ncols(),
+            shift,
+            weights: matrix
+                .iter()
+                .map(|weight| W::saturate((weight * factor).round() as i64))
+                .collect(),
+        }
+    }
+
+    pub fn scale(&self) -> f32 {
+        1.0 / (1u64 << self.shift) as f32
+    }
+
+    fn apply(&self, inputs: &[i32], activation: fn(i32) -> i32) -> Vec<i32> {
+        let rounding = (1i64 << self.shift) >> 1;
+        self.weights
+            .chunks(self.inputs)
+            .map(|column| {
+                let sum: i64 = inputs
+                    .iter()
+                    .zip(column)
+                    .map(|(input, weight)| *input as i64 * (*weight).into())
+                    .sum();
+                let value = (sum + rounding) >> self.shift;
+                activation(value.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct QuantizationReport {
+    pub max_deviation: f32,
+    pub mean_deviation: f32,
+}
+
+/**
+ * Individual with integer weights, created with `Individual::quantize`
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QuantizedIndividual<W, const INPUTS: usize, const OUTPUTS: usize> {
+    pub layers: Vec<QuantizedLayer<W>>,
+}
+
+impl<W: QuantizedWeight, const INPUTS: usize, const OUTPUTS: usize>
+    QuantizedIndividual<W, INPUTS, OUTPUTS>
+{
+    /**
+     * Evaluates the network on fixed-point inputs using only integer
+     * arithmetic
+     */
+    pub fn evaluate_fixed(&self, inputs: [i32; INPUTS]) -> [i32; OUTPUTS] {
+        let (output_layer, hidden_layers) = self
+            .layers
+            .split_last()
+            .expect("There is always an output layer");
+        let hidden = hidden_layers.iter().fold(inputs.to_vec(), |values, layer| {
+            layer.apply(&values, leaky_relu)
+        });
+
+        output_layer
+            .apply(&hidden, sigmoid)
+            .try_into()
+            .expect("The output layer has OUTPUTS columns")
+    }
+
+    pub fn evaluate(&self, inputs: [f32; INPUTS]) -> [f32; OUTPUTS] {
+        self.evaluate_fixed(inputs.map(to_fixed)).map(from_fixed)
+    }
+
+    /**
+     * Difference between the outputs of this and the original network over
+     * the samples
+     */
+    pub fn deviation<const LAYERS: usize, const SUBLAYERS: usize>(
+        &self,
+        individual: &Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        samples: &[[f32; INPUTS]],
+    ) -> QuantizationReport {
+        let deviations: Vec<f32> = samples
+            .iter()
+            .flat_map(|sample| {
+                self.evaluate(*sample)
+                    .into_iter()
+                    .zip(individual.evaluate(*sample))
+                    .map(|(a, b)| (a - b).abs())
+            })
+            .collect();
+
+        if deviations.is_empty() {
+            return QuantizationReport::default();
+        }
+
+        QuantizationReport {
+            max_deviation: deviations.iter().copied().fold(0.0, f32::max),
+            mean_deviation: deviations.iter().sum::<f32>() / deviations.len() as f32,
+        }
+    }
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Converts the weights to `i8` or `i16` with a scale per layer
+     */
+    pub fn quantize<W: QuantizedWeight>(&self) -> QuantizedIndividual<W, INPUTS, OUTPUTS> {
+        QuantizedIndividual {
+            layers: self.layers().iter().map(QuantizedLayer::new).collect(),
+        }
+    }
+}
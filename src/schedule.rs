@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * A parameter that changes over the generations
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Schedule {
+    Constant(f32),
+    /// Moves linearly from `start` to `end` over `generations`, then stays at `end`
+    Linear {
+        start: f32,
+        end: f32,
+        generations: usize,
+    },
+    /// `start * rate ^ generation`, never below `min`
+    Exponential {
+        start: f32,
+        rate: f32,
+        min: f32,
+    },
+    /// Rechenberg's 1/5th success rule: `value` is multiplied by `factor` when
+    /// more than a fifth of the offspring beat their parent and divided by it
+    /// otherwise, staying within `min..=max`
+    OneFifth {
+        value: f32,
+        factor: f32,
+        min: f32,
+        max: f32,
+    },
+}
+
+impl Schedule {
+    pub fn value(&self, generation: usize) -> f32 {
+        match *self {
+            Schedule::Constant(value) => value,
+            Schedule::Linear {
+                start,
+                end,
+                generations,
+            } => {
+                let progress = (generation as f32 / generations.max(1) as f32).min(1.0);
+                start + (end - start) * progress
+            }
+            Schedule::Exponential { start, rate, min } => {
+                (start * rate.powi(generation.min(i32::MAX as usize) as i32)).max(min)
+            }
+            Schedule::OneFifth { value, .. } => value,
+        }
+    }
+
+    /**
+     * Feeds the fraction of offspring that were fitter than their parent to
+     * adaptive schedules, other schedules ignore it
+     */
+    pub fn adapt(&mut self, success_rate: f32) {
+        if let Schedule::OneFifth {
+            value,
+            factor,
+            min,
+            max,
+        } = self
+        {
+            *value = if success_rate > 0.2 {
+                *value * *factor
+            } else if success_rate < 0.2 {
+                *value / *factor
+            } else {
+                *value
+            }
+            .clamp(*min, *max);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{initializer::gaussian, pick_weight, Individual};
+
+/**
+ * Where the mutation step size of an offspring comes from
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum MutationMode {
+    /// The population's `mutation_strength`
+    #[default]
+    Global,
+    /// The individual's own `step_sizes`, inherited from its parent and
+    /// mutated log-normally with `learning_rate` before every use
+    SelfAdaptive { learning_rate: f32, min_step: f32 },
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Evolution strategy style mutation: every step size is first multiplied by
+     * `exp(learning_rate * N(0, 1))`, then `mutations` weights receive Gaussian
+     * noise scaled by the step size of their matrix. Step sizes start at
+     * `initial_step`.
+     */
+    pub fn mutate_self_adaptive<RNG: Rng>(
+        &mut self,
+        mutations: usize,
+        initial_step: f32,
+        learning_rate: f32,
+        min_step: f32,
+        rng: &mut RNG,
+    ) {
+        if self.step_sizes.len() != LAYERS + 2 {
+            self.step_sizes = vec![initial_step; LAYERS + 2];
+        }
+        for step in self.step_sizes.iter_mut() {
+            *step = (*step * (learning_rate * gaussian(rng)).exp()).max(min_step);
+        }
+
+        for _ in 0..mutations {
+            let layer = rng.gen_range(0..LAYERS + 2);
+            let noise = gaussian(rng) * self.step_sizes[layer];
+            if layer == 0 {
+                let position = pick_weight(&self.input_matrix, rng);
+                self.input_matrix[position] += noise;
+            } else if layer == LAYERS + 1 {
+                let position = pick_weight(&self.output_matrix, rng);
+                self.output_matrix[position] += noise;
+            } else {
+                let position = pick_weight(&self.matricies[layer - 1], rng);
+                self.matricies[layer - 1][position] += noise;
+            }
+        }
+    }
+}
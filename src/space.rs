@@ -0,0 +1,320 @@
+//! Rendering-free spaceship world of the space simulation example.
+//!
+//! Spaceships steer with four thrusters towards food while avoiding walls.
+//! The world can be stepped headless for training, and the ggez example only
+//! draws it.
+
+mod arena;
+mod locations;
+pub mod sensors;
+
+pub use arena::{Arena, ArenaGenerator};
+pub use locations::{FOOD_LOCATIONS, WALL_LOCATIONS};
+pub use sensors::{Bias, Gradients, RayCasts, RelativeVelocity, Sensors};
+use serde::{Deserialize, Serialize};
+
+use crate::Individual;
+
+pub type Vector = nalgebra::Vector2<f32>;
+
+/// Thrust direction and position relative to the ship for the first outputs
+pub const THRUSTERS: [(Vector, Vector); 4] = [
+    (Vector::new(1., 0.), Vector::new(0., 0.)),
+    (Vector::new(-1., 0.), Vector::new(0., 0.)),
+    (Vector::new(0., -1.), Vector::new(1., 0.)),
+    (Vector::new(0., 1.), Vector::new(1., 0.)),
+];
+
+/// Fitness gained for each piece of food eaten
+pub const FOOD_REWARD: f32 = 1.0;
+/// Fitness gained per step survived, only paid out on crashing
+pub const SURVIVAL_REWARD: f32 = 0.001;
+
+const FOOD_RADIUS_SQUARED: f32 = 2.0;
+const WALL_RADIUS_SQUARED: f32 = 10.0;
+
+/**
+ * Rotates `vector` counter-clockwise by `angle` radians
+ */
+pub fn rotate(vector: Vector, angle: f32) -> Vector {
+    let (sin, cos) = angle.sin_cos();
+    Vector::new(
+        vector.x * cos - vector.y * sin,
+        vector.x * sin + vector.y * cos,
+    )
+}
+
+/**
+ * `vector` rotated by 90 degrees counter-clockwise
+ */
+pub fn perpendicular(vector: Vector) -> Vector {
+    Vector::new(-vector.y, vector.x)
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Spaceship<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+> {
+    pub brain: Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    /// Which food is still available to this ship, every ship has its own
+    pub food: Vec<bool>,
+    pub location: Vector,
+    pub angle: f32,
+    pub alive: bool,
+    pub velocity: Vector,
+    pub angular_velocity: f32,
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    const MASS: f32 = 4.0;
+    const MOMENT_OF_INERTIA: f32 = 16.0;
+
+    pub fn new(brain: Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>, food_count: usize) -> Self {
+        Spaceship {
+            brain,
+            food: vec![true; food_count],
+            location: Vector::zeros(),
+            angle: 0.0,
+            alive: true,
+            velocity: Vector::zeros(),
+            angular_velocity: 0.0,
+        }
+    }
+
+    pub fn facing(&self) -> Vector {
+        Vector::new(self.angle.cos(), self.angle.sin())
+    }
+
+    pub fn food_eaten(&self) -> usize {
+        self.food.iter().filter(|available| !**available).count()
+    }
+
+    /**
+     * Stores the food eaten and survival time as objectives and the final
+     * location as the behavior descriptor
+     */
+    pub fn record_outcome(&mut self, steps: u32) {
+        self.brain.objectives = vec![self.food_eaten() as f32, steps as f32];
+        self.brain.behavior = vec![self.location.x, self.location.y];
+    }
+
+    /**
+     * Applies `force`, given relative to the ship, at `position` relative to
+     * the centre of mass
+     */
+    pub fn apply_force(&mut self, force: Vector, position: Vector) {
+        self.angular_velocity +=
+            Vector::new(position.y, position.x).dot(&force) / Self::MOMENT_OF_INERTIA;
+        self.velocity += rotate(force, self.angle) / Self::MASS;
+    }
+
+    /**
+     * Damps the velocities, fires the thrusters with the first four brain
+     * outputs and moves the ship
+     */
+    pub fn physics_step(&mut self, outputs: &[f32; OUTPUTS]) {
+        const { assert!(OUTPUTS >= THRUSTERS.len(), "Every thruster needs an output") };
+
+        self.angular_velocity *= 0.9;
+        self.velocity *= 0.99;
+
+        for (force, (direction, position)) in outputs.iter().zip(THRUSTERS) {
+            self.apply_force(direction * *force, position);
+        }
+
+        self.location += self.velocity;
+        self.angle += self.angular_velocity;
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct World<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+    SENSORS = Gradients,
+> {
+    pub arena: Arena,
+    pub sensors: SENSORS,
+    pub ships: Vec<Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    /// Steps since the start of the round
+    pub steps: u32,
+    /// How many ships ate each piece of food this round
+    pub food_eaten: Vec<u32>,
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    World<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * World with the original food and wall layout and the original
+     * gradient sensors
+     */
+    pub fn new(
+        brains: impl IntoIterator<Item = Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    ) -> Self {
+        Self::in_arena(Arena::original(), brains)
+    }
+
+    pub fn in_arena(
+        arena: Arena,
+        brains: impl IntoIterator<Item = Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    ) -> Self {
+        Self::with_sensors(arena, Gradients, brains)
+    }
+}
+
+impl<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+        SENSORS: Sensors,
+    > World<INPUTS, LAYERS, OUTPUTS, SUBLAYERS, SENSORS>
+{
+    pub fn with_sensors(
+        arena: Arena,
+        sensors: SENSORS,
+        brains: impl IntoIterator<Item = Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>,
+    ) -> Self {
+        let ships = brains
+            .into_iter()
+            .map(|brain| Spaceship::new(brain, arena.food.len()))
+            .collect();
+
+        World {
+            food_eaten: vec![0; arena.food.len()],
+            arena,
+            sensors,
+            ships,
+            steps: 0,
+        }
+    }
+
+    pub fn living_ships(&self) -> usize {
+        self.ships.iter().filter(|ship| ship.alive).count()
+    }
+
+    /**
+     * Advances every living ship by one step, feeding the sensors to its brain
+     * and rewarding food eaten. Ships hitting a wall die. Returns the number of
+     * ships still alive.
+     */
+    pub fn step(&mut self) -> usize {
+        self.steps += 1;
+
+        for ship in self.ships.iter_mut().filter(|ship| ship.alive) {
+            let inputs = sensors::sense(&self.sensors, ship, &self.arena);
+            let outputs = ship.brain.evaluate(inputs);
+            ship.physics_step(&outputs);
+
+            for (index, food) in self.arena.food.iter().enumerate() {
+                if ship.food[index] && (ship.location - food).norm_squared() <= FOOD_RADIUS_SQUARED
+                {
+                    ship.food[index] = false;
+                    ship.brain.fitness += FOOD_REWARD;
+                    self.food_eaten[index] += 1;
+                }
+            }
+
+            if self
+                .arena
+                .walls
+                .iter()
+                .any(|wall| (ship.location - wall).norm_squared() <= WALL_RADIUS_SQUARED)
+            {
+                ship.alive = false;
+                ship.brain.fitness += self.steps as f32 * SURVIVAL_REWARD;
+                ship.record_outcome(self.steps);
+            }
+        }
+
+        self.living_ships()
+    }
+
+    /**
+     * Steps until every ship is dead or `max_steps` is reached, then records
+     * the outcome of the survivors. Returns the number of steps taken.
+     */
+    pub fn run(&mut self, max_steps: u32) -> u32 {
+        while self.steps < max_steps && self.step() > 0 {}
+        self.finish();
+        self.steps
+    }
+
+    /**
+     * Records the outcome of every ship still alive at the end of a round
+     */
+    pub fn finish(&mut self) {
+        for ship in self.ships.iter_mut().filter(|ship| ship.alive) {
+            ship.record_outcome(self.steps);
+        }
+    }
+
+    pub fn best_fitness(&self) -> f32 {
+        self.ships
+            .iter()
+            .map(|ship| ship.brain.fitness)
+            .reduce(f32::max)
+            .unwrap_or(0.0)
+    }
+
+    pub fn into_brains(
+        self,
+    ) -> impl Iterator<Item = Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>> {
+        self.ships.into_iter().map(|ship| ship.brain)
+    }
+}
+
+/**
+ * Runs every brain through each arena for at most `max_steps` steps, with the
+ * ships of one arena flying together. The fitness and objectives become the
+ * mean over the arenas and the behavior descriptors are concatenated, so a
+ * brain can't score well by memorising a single map.
+ */
+pub fn evaluate_in_arenas<
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+    SENSORS: Sensors + Clone,
+>(
+    brains: &mut [Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+    arenas: &[Arena],
+    sensors: &SENSORS,
+    max_steps: u32,
+) {
+    for brain in brains.iter_mut() {
+        brain.reset_evaluation();
+    }
+    if arenas.is_empty() {
+        return;
+    }
+
+    for arena in arenas {
+        let mut world = World::with_sensors(
+            arena.clone(),
+            sensors.clone(),
+            brains.iter().cloned().map(|mut brain| {
+                brain.reset_evaluation();
+                brain
+            }),
+        );
+        world.run(max_steps);
+
+        for (brain, ship) in brains.iter_mut().zip(world.ships) {
+            brain.fitness += ship.brain.fitness / arenas.len() as f32;
+            brain.objectives.resize(ship.brain.objectives.len(), 0.0);
+            for (total, objective) in brain.objectives.iter_mut().zip(&ship.brain.objectives) {
+                *total += objective / arenas.len() as f32;
+            }
+            brain.behavior.extend(ship.brain.behavior);
+        }
+    }
+}
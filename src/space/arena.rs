@@ -0,0 +1,165 @@
+use std::{fs, io, path::Path};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::{Vector, FOOD_LOCATIONS, WALL_LOCATIONS};
+
+/**
+ * Food and wall layout of a world
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Arena {
+    pub food: Vec<Vector>,
+    pub walls: Vec<Vector>,
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena::original()
+    }
+}
+
+impl Arena {
+    /**
+     * The hand-made arena the space simulation started with
+     */
+    pub fn original() -> Self {
+        Arena {
+            food: FOOD_LOCATIONS.to_vec(),
+            walls: WALL_LOCATIONS.to_vec(),
+        }
+    }
+
+    /**
+     * Walls every `spacing` units along the edges of the square from
+     * `-half_size` to `half_size`, panics unless `spacing` is positive
+     */
+    pub fn border(half_size: f32, spacing: f32) -> Vec<Vector> {
+        assert!(
+            spacing > 0.0,
+            "The wall spacing has to be positive, got {}",
+            spacing
+        );
+        let per_side = (2.0 * half_size / spacing).round().max(1.0) as usize;
+        let corners = [
+            Vector::new(-half_size, -half_size),
+            Vector::new(half_size, -half_size),
+            Vector::new(half_size, half_size),
+            Vector::new(-half_size, half_size),
+        ];
+
+        (0..4)
+            .flat_map(|side| {
+                let start = corners[side];
+                let end = corners[(side + 1) % 4];
+                (0..per_side).map(move |i| start + (end - start) * (i as f32 / per_side as f32))
+            })
+            .collect()
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Arenas only contain numbers")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::from_json(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
+
+/**
+ * Settings for procedurally generated arenas
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ArenaGenerator {
+    /// The border walls go around the square from `-half_size` to `half_size`
+    pub half_size: f32,
+    /// Distance between the border walls, has to be positive
+    pub wall_spacing: f32,
+    pub food_count: usize,
+    /// Single walls scattered inside the border
+    pub obstacle_count: usize,
+    /// Radius around the spawn point kept free of obstacles
+    pub spawn_clearance: f32,
+    /// Food is never placed closer than this to a wall
+    pub wall_clearance: f32,
+}
+
+impl Default for ArenaGenerator {
+    fn default() -> Self {
+        ArenaGenerator {
+            half_size: 50.0,
+            wall_spacing: 5.0,
+            food_count: 100,
+            obstacle_count: 12,
+            spawn_clearance: 8.0,
+            wall_clearance: 5.0,
+        }
+    }
+}
+
+impl ArenaGenerator {
+    /**
+     * Random arena, the same seed always gives the same arena. Cramped
+     * settings can give fewer obstacles and food than asked for.
+     */
+    pub fn generate(&self, seed: u64) -> Arena {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut walls = Arena::border(self.half_size, self.wall_spacing);
+        let inner = (self.half_size - self.wall_clearance).max(0.0);
+
+        // Gives up on obstacles and food that do not fit rather than looping
+        // forever
+        let mut obstacles = 0;
+        for _ in 0..self.obstacle_count * 100 {
+            if obstacles == self.obstacle_count {
+                break;
+            }
+            let location = Self::random_location(inner, &mut rng);
+            if location.norm() >= self.spawn_clearance {
+                walls.push(location);
+                obstacles += 1;
+            }
+        }
+
+        let mut food = Vec::with_capacity(self.food_count);
+        for _ in 0..self.food_count * 100 {
+            if food.len() == self.food_count {
+                break;
+            }
+            let location = Self::random_location(inner, &mut rng);
+            if walls
+                .iter()
+                .all(|wall| (wall - location).norm() >= self.wall_clearance)
+            {
+                food.push(location);
+            }
+        }
+
+        Arena { food, walls }
+    }
+
+    /**
+     * `count` arenas from consecutive seeds
+     */
+    pub fn generate_many(&self, seed: u64, count: usize) -> Vec<Arena> {
+        (0..count as u64)
+            .map(|i| self.generate(seed.wrapping_add(i)))
+            .collect()
+    }
+
+    fn random_location(half_size: f32, rng: &mut impl Rng) -> Vector {
+        Vector::new(
+            rng.gen_range(-half_size..=half_size),
+            rng.gen_range(-half_size..=half_size),
+        )
+    }
+}
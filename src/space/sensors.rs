@@ -0,0 +1,372 @@
+//! Sensor layouts turning the world around a ship into brain inputs.
+//!
+//! A layout is a type implementing `Sensors`, and tuples of layouts read each
+//! part in order. The number of readings has to equal the brain's `INPUTS`,
+//! which `sense` checks at compile time, so a layout can be used to spell out
+//! the brain type: `Individual<{ <(RayCasts<8>, Bias) as Sensors>::INPUTS }, ..>`.
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    perpendicular, rotate, Arena, Spaceship, Vector, FOOD_RADIUS_SQUARED, WALL_RADIUS_SQUARED,
+};
+
+pub trait Sensors {
+    /// Number of readings, the brain must have this many inputs
+    const INPUTS: usize;
+
+    /**
+     * Writes the readings for `ship` into `readings`, which has exactly
+     * `INPUTS` entries
+     */
+    fn read<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    >(
+        &self,
+        ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        arena: &Arena,
+        readings: &mut [f32],
+    );
+}
+
+/**
+ * Reads every sensor of the layout into the brain inputs
+ */
+pub fn sense<
+    SENSORS: Sensors,
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+>(
+    sensors: &SENSORS,
+    ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    arena: &Arena,
+) -> [f32; INPUTS] {
+    const {
+        assert!(
+            SENSORS::INPUTS == INPUTS,
+            "The brain needs exactly one input per sensor reading"
+        )
+    };
+
+    let mut readings = [0.0; INPUTS];
+    sensors.read(ship, arena, &mut readings);
+    readings
+}
+
+macro_rules! tuple_sensors {
+    ($($sensor:ident $index:tt),+) => {
+        impl<$($sensor: Sensors),+> Sensors for ($($sensor,)+) {
+            const INPUTS: usize = 0 $(+ $sensor::INPUTS)+;
+
+            #[allow(unused_assignments)]
+            fn read<
+                const INPUTS: usize,
+                const LAYERS: usize,
+                const OUTPUTS: usize,
+                const SUBLAYERS: usize,
+            >(
+                &self,
+                ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+                arena: &Arena,
+                readings: &mut [f32],
+            ) {
+                let mut start = 0;
+                $(
+                    self.$index.read(ship, arena, &mut readings[start..start + $sensor::INPUTS]);
+                    start += $sensor::INPUTS;
+                )+
+            }
+        }
+    };
+}
+
+tuple_sensors!(A 0, B 1);
+tuple_sensors!(A 0, B 1, C 2);
+tuple_sensors!(A 0, B 1, C 2, D 3);
+
+/// Scale applied to the inverse square sums so they are around 1
+const GRADIENT_SCALE: f32 = 16.0;
+
+/**
+ * Inverse square weighted sums of `(1, forward, sideways)` components of the
+ * directions to every target
+ */
+fn gradient(
+    ship_location: Vector,
+    facing: Vector,
+    targets: impl Iterator<Item = Vector>,
+) -> [f32; 3] {
+    let mut sums = [0.0; 3];
+    for target in targets {
+        let offset = target - ship_location;
+        let distance_squared = offset.norm_squared().max(1.0);
+        let direction = offset.normalize();
+
+        sums[0] += 1.0 / distance_squared;
+        sums[1] += direction.dot(&facing) / distance_squared;
+        sums[2] += direction.dot(&perpendicular(facing)) / distance_squared;
+    }
+    sums.map(|sum| sum * GRADIENT_SCALE)
+}
+
+/**
+ * Locations of the food still available to `ship`
+ */
+fn available_food<
+    'a,
+    const INPUTS: usize,
+    const LAYERS: usize,
+    const OUTPUTS: usize,
+    const SUBLAYERS: usize,
+>(
+    ship: &'a Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+    arena: &'a Arena,
+) -> impl Iterator<Item = Vector> + 'a {
+    arena
+        .food
+        .iter()
+        .zip(&ship.food)
+        .filter(|(_, available)| **available)
+        .map(|(food, _)| *food)
+}
+
+/**
+ * The original sensors of the space simulation: food gradients, velocity in
+ * the ship's frame, wall gradients, angular velocity and a constant bias
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct Gradients;
+
+impl Sensors for Gradients {
+    const INPUTS: usize = 10;
+
+    fn read<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    >(
+        &self,
+        ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        arena: &Arena,
+        readings: &mut [f32],
+    ) {
+        let facing = ship.facing();
+        let food = gradient(ship.location, facing, available_food(ship, arena));
+        let walls = gradient(ship.location, facing, arena.walls.iter().copied());
+
+        readings.copy_from_slice(&[
+            food[0],
+            food[1],
+            food[2],
+            ship.velocity.dot(&facing),
+            ship.velocity.dot(&perpendicular(facing)),
+            walls[0],
+            walls[1],
+            walls[2],
+            ship.angular_velocity,
+            1.0,
+        ]);
+    }
+}
+
+/**
+ * A constant 1, lets the brain shift its activations
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct Bias;
+
+impl Sensors for Bias {
+    const INPUTS: usize = 1;
+
+    fn read<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    >(
+        &self,
+        _ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        _arena: &Arena,
+        readings: &mut [f32],
+    ) {
+        readings[0] = 1.0;
+    }
+}
+
+/**
+ * Velocity in the ship's frame, angular velocity, and how fast the ship is
+ * closing in on the nearest food and the nearest wall
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct RelativeVelocity;
+
+impl RelativeVelocity {
+    /**
+     * Velocity towards the nearest target, zero without targets
+     */
+    fn closing_speed(
+        location: Vector,
+        velocity: Vector,
+        targets: impl Iterator<Item = Vector>,
+    ) -> f32 {
+        targets
+            .map(|target| target - location)
+            .min_by(|a, b| a.norm_squared().total_cmp(&b.norm_squared()))
+            .and_then(|offset| offset.try_normalize(f32::EPSILON))
+            .map_or(0.0, |direction| velocity.dot(&direction))
+    }
+}
+
+impl Sensors for RelativeVelocity {
+    const INPUTS: usize = 5;
+
+    fn read<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    >(
+        &self,
+        ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        arena: &Arena,
+        readings: &mut [f32],
+    ) {
+        let facing = ship.facing();
+        readings.copy_from_slice(&[
+            ship.velocity.dot(&facing),
+            ship.velocity.dot(&perpendicular(facing)),
+            ship.angular_velocity,
+            Self::closing_speed(ship.location, ship.velocity, available_food(ship, arena)),
+            Self::closing_speed(ship.location, ship.velocity, arena.walls.iter().copied()),
+        ]);
+    }
+}
+
+/// Object type reading of a ray hitting food
+pub const RAY_FOOD: f32 = 1.0;
+/// Object type reading of a ray hitting a wall
+pub const RAY_WALL: f32 = -1.0;
+/// Object type reading of a ray hitting nothing within range
+pub const RAY_NOTHING: f32 = 0.0;
+
+/**
+ * `RAYS` rays spread evenly over the field of view, centred on the ship's
+ * facing direction. Each ray gives two readings: how close the first object
+ * hit is, from 1 at the ship to 0 at `range` or when nothing is hit, and the
+ * object type as `RAY_FOOD`, `RAY_WALL` or `RAY_NOTHING`. Food and walls are
+ * circles with the radius at which ships eat or crash into them.
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RayCasts<const RAYS: usize> {
+    /// Angle between the outermost rays in radians
+    pub field_of_view: f32,
+    pub range: f32,
+}
+
+impl<const RAYS: usize> Default for RayCasts<RAYS> {
+    fn default() -> Self {
+        RayCasts {
+            field_of_view: std::f32::consts::PI,
+            range: 40.0,
+        }
+    }
+}
+
+/**
+ * Distance along the ray from `origin` in the unit `direction` to the
+ * circle, zero if the origin is inside it
+ */
+fn ray_circle(
+    origin: Vector,
+    direction: Vector,
+    centre: Vector,
+    radius_squared: f32,
+) -> Option<f32> {
+    let offset = centre - origin;
+    if offset.norm_squared() <= radius_squared {
+        return Some(0.0);
+    }
+
+    let along = offset.dot(&direction);
+    let miss_squared = offset.norm_squared() - along * along;
+    if along < 0.0 || miss_squared > radius_squared {
+        return None;
+    }
+    Some(along - (radius_squared - miss_squared).sqrt())
+}
+
+impl<const RAYS: usize> RayCasts<RAYS> {
+    /**
+     * Angle of each ray relative to the facing direction
+     */
+    pub fn ray_angles(&self) -> [f32; RAYS] {
+        std::array::from_fn(|ray| {
+            if RAYS == 1 {
+                0.0
+            } else {
+                self.field_of_view * (ray as f32 / (RAYS - 1) as f32 - 0.5)
+            }
+        })
+    }
+
+    /**
+     * Distance and object type of the first object hit by a ray, if any is
+     * within range
+     */
+    pub fn cast<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    >(
+        &self,
+        ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        arena: &Arena,
+        angle: f32,
+    ) -> Option<(f32, f32)> {
+        let direction = rotate(ship.facing(), angle);
+        let food = available_food(ship, arena).filter_map(|food| {
+            ray_circle(ship.location, direction, food, FOOD_RADIUS_SQUARED)
+                .map(|distance| (distance, RAY_FOOD))
+        });
+        let walls = arena.walls.iter().filter_map(|wall| {
+            ray_circle(ship.location, direction, *wall, WALL_RADIUS_SQUARED)
+                .map(|distance| (distance, RAY_WALL))
+        });
+
+        food.chain(walls)
+            .filter(|(distance, _)| *distance <= self.range)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+    }
+}
+
+impl<const RAYS: usize> Sensors for RayCasts<RAYS> {
+    const INPUTS: usize = 2 * RAYS;
+
+    fn read<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    >(
+        &self,
+        ship: &Spaceship<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>,
+        arena: &Arena,
+        readings: &mut [f32],
+    ) {
+        for (angle, reading) in self.ray_angles().into_iter().zip(readings.chunks_mut(2)) {
+            let (closeness, object) = match self.cast(ship, arena, angle) {
+                Some((distance, object)) => (1.0 - distance / self.range, object),
+                None => (0.0, RAY_NOTHING),
+            };
+            reading[0] = closeness;
+            reading[1] = object;
+        }
+    }
+}
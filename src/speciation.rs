@@ -0,0 +1,126 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::Individual;
+
+/**
+ * What `evolve` compares to decide whether two individuals belong to the same
+ * species
+ */
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum Speciation {
+    /// The population's `distance_metric` below its `speciation_threshold`
+    #[default]
+    Genome,
+    /// The mean squared difference of the outputs on the population's `probes`
+    /// below `threshold`. Falls back to `Genome` while there are no probes.
+    Behavior { threshold: f32 },
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+struct Probe<const INPUTS: usize>(#[serde(with = "crate::serde_arrays")] [f32; INPUTS]);
+
+/**
+ * Reference inputs used to compare individuals by behavior, either supplied
+ * up front or sampled with `record` from the inputs seen during evaluation.
+ * Each generation the sample is refreshed from the inputs recorded since the
+ * previous one.
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ProbeSet<const INPUTS: usize> {
+    probes: Vec<Probe<INPUTS>>,
+    pub capacity: usize,
+    recorded: usize,
+}
+
+impl<const INPUTS: usize> Default for ProbeSet<INPUTS> {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+impl<const INPUTS: usize> ProbeSet<INPUTS> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            probes: Vec::new(),
+            capacity,
+            recorded: 0,
+        }
+    }
+
+    pub fn from_inputs(inputs: Vec<[f32; INPUTS]>) -> Self {
+        Self {
+            capacity: inputs.len(),
+            probes: inputs.into_iter().map(Probe).collect(),
+            recorded: 0,
+        }
+    }
+
+    pub fn inputs(&self) -> impl Iterator<Item = &[f32; INPUTS]> {
+        self.probes.iter().map(|probe| &probe.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.probes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.probes.is_empty()
+    }
+
+    /**
+     * Reservoir sampling of the inputs recorded this generation
+     */
+    pub fn record<RNG: Rng>(&mut self, inputs: [f32; INPUTS], rng: &mut RNG) {
+        if self.recorded < self.capacity {
+            if self.recorded < self.probes.len() {
+                self.probes[self.recorded] = Probe(inputs);
+            } else {
+                self.probes.push(Probe(inputs));
+            }
+        } else {
+            let index = rng.gen_range(0..=self.recorded);
+            if index < self.capacity {
+                self.probes[index] = Probe(inputs);
+            }
+        }
+        self.recorded += 1;
+    }
+
+    /**
+     * Starts a new sample, the current probes are kept until they are
+     * overwritten by newly recorded inputs
+     */
+    pub fn start_generation(&mut self) {
+        self.recorded = 0;
+    }
+}
+
+/**
+ * Mean squared difference between two sets of outputs
+ */
+pub(crate) fn output_distance(one: &[f32], two: &[f32]) -> f32 {
+    one.iter()
+        .zip(two)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f32>()
+        / one.len().max(1) as f32
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * The outputs for every probe, concatenated
+     */
+    pub fn probe_outputs(&self, probes: &ProbeSet<INPUTS>) -> Vec<f32> {
+        probes
+            .inputs()
+            .flat_map(|inputs| self.evaluate(*inputs))
+            .collect()
+    }
+
+    pub fn behavioral_distance(&self, other: &Self, probes: &ProbeSet<INPUTS>) -> f32 {
+        output_distance(&self.probe_outputs(probes), &other.probe_outputs(probes))
+    }
+}
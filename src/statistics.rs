@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{distance::DistanceMetric, diversity::mean_pairwise_distance, Individual};
+
+/**
+ * Summary of one evaluated generation, taken just before it is replaced
+ */
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GenerationStatistics {
+    pub generation: usize,
+    pub best_fitness: f32,
+    pub mean_fitness: f32,
+    pub median_fitness: f32,
+    pub worst_fitness: f32,
+    pub fitness_std_dev: f32,
+    pub species_count: usize,
+    pub species_sizes: Vec<usize>,
+    /// Mean distance over all pairs of individuals, in the population's metric
+    pub mean_genome_distance: f32,
+    /// Mean number of non-zero weights per individual
+    pub mean_connections: f32,
+}
+
+impl GenerationStatistics {
+    pub fn new<
+        const INPUTS: usize,
+        const LAYERS: usize,
+        const OUTPUTS: usize,
+        const SUBLAYERS: usize,
+    >(
+        generation: usize,
+        individuals: &[Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>],
+        species: &[Vec<Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>>],
+        metric: DistanceMetric,
+    ) -> Self {
+        let count = individuals.len().max(1) as f32;
+
+        let mut fitness: Vec<f32> = individuals.iter().map(|i| i.fitness).collect();
+        fitness.sort_by(f32::total_cmp);
+
+        let mean_fitness = fitness.iter().sum::<f32>() / count;
+        let variance = fitness
+            .iter()
+            .map(|i| (i - mean_fitness) * (i - mean_fitness))
+            .sum::<f32>()
+            / count;
+        let median_fitness = match fitness.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => (fitness[len / 2 - 1] + fitness[len / 2]) / 2.0,
+            len => fitness[len / 2],
+        };
+
+        Self {
+            generation,
+            best_fitness: fitness.last().copied().unwrap_or(0.0),
+            mean_fitness,
+            median_fitness,
+            worst_fitness: fitness.first().copied().unwrap_or(0.0),
+            fitness_std_dev: variance.sqrt(),
+            species_count: species.len(),
+            species_sizes: species.iter().map(Vec::len).collect(),
+            mean_genome_distance: mean_pairwise_distance(individuals, metric),
+            mean_connections: individuals
+                .iter()
+                .map(|i| i.connection_count())
+                .sum::<usize>() as f32
+                / count,
+        }
+    }
+}
+
+/**
+ * Statistics of every generation of a population, oldest first
+ */
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    generations: Vec<GenerationStatistics>,
+}
+
+impl History {
+    pub fn push(&mut self, statistics: GenerationStatistics) {
+        self.generations.push(statistics);
+    }
+
+    pub fn generations(&self) -> &[GenerationStatistics] {
+        &self.generations
+    }
+
+    pub fn len(&self) -> usize {
+        self.generations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.generations.is_empty()
+    }
+
+    pub fn latest(&self) -> Option<&GenerationStatistics> {
+        self.generations.last()
+    }
+
+    pub fn get(&self, generation: usize) -> Option<&GenerationStatistics> {
+        self.generations.iter().find(|i| i.generation == generation)
+    }
+
+    /**
+     * The generation with the highest best fitness
+     */
+    pub fn best_generation(&self) -> Option<&GenerationStatistics> {
+        self.generations
+            .iter()
+            .max_by(|i, j| i.best_fitness.total_cmp(&j.best_fitness))
+    }
+
+    pub fn best_fitness(&self) -> Vec<f32> {
+        self.generations.iter().map(|i| i.best_fitness).collect()
+    }
+
+    pub fn mean_fitness(&self) -> Vec<f32> {
+        self.generations.iter().map(|i| i.mean_fitness).collect()
+    }
+
+    pub fn species_count(&self) -> Vec<usize> {
+        self.generations.iter().map(|i| i.species_count).collect()
+    }
+
+    /**
+     * Number of generations since the best fitness last improved
+     */
+    pub fn generations_without_improvement(&self) -> usize {
+        let mut best = f32::NEG_INFINITY;
+        let mut since = 0;
+        for statistics in &self.generations {
+            if statistics.best_fitness > best {
+                best = statistics.best_fitness;
+                since = 0;
+            } else {
+                since += 1;
+            }
+        }
+        since
+    }
+}
@@ -0,0 +1,188 @@
+//! Export of network topology as Graphviz DOT or standalone SVG.
+//!
+//! Positive weights are drawn blue and negative ones red, with the line width
+//! proportional to the magnitude relative to the largest weight. Zero weights
+//! are left out.
+
+use std::fmt::Write;
+
+use crate::Individual;
+
+const POSITIVE_COLOR: &str = "#1f77b4";
+const NEGATIVE_COLOR: &str = "#d62728";
+const MIN_WIDTH: f32 = 0.25;
+const MAX_WIDTH: f32 = 4.0;
+
+const LAYER_SPACING: f32 = 160.0;
+const NODE_SPACING: f32 = 40.0;
+const NODE_RADIUS: f32 = 10.0;
+const MARGIN: f32 = 40.0;
+
+/**
+ * One edge per non-zero weight as `(layer, from, to, weight)`, where `from`
+ * is a node in `layer` and `to` a node in `layer + 1`
+ */
+fn edges(layers: &[nalgebra::DMatrix<f32>]) -> Vec<(usize, usize, usize, f32)> {
+    let mut edges = Vec::new();
+    for (layer, matrix) in layers.iter().enumerate() {
+        for to in 0..matrix.ncols() {
+            for from in 0..matrix.nrows() {
+                let weight = matrix[(from, to)];
+                if weight != 0.0 {
+                    edges.push((layer, from, to, weight));
+                }
+            }
+        }
+    }
+    edges
+}
+
+fn layer_name(layer: usize, count: usize) -> String {
+    if layer == 0 {
+        "input".to_string()
+    } else if layer + 1 == count {
+        "output".to_string()
+    } else {
+        format!("hidden {}", layer)
+    }
+}
+
+fn color(weight: f32) -> &'static str {
+    if weight > 0.0 {
+        POSITIVE_COLOR
+    } else {
+        NEGATIVE_COLOR
+    }
+}
+
+fn line_width(weight: f32, max: f32) -> f32 {
+    MIN_WIDTH + (MAX_WIDTH - MIN_WIDTH) * weight.abs() / max
+}
+
+impl<const INPUTS: usize, const LAYERS: usize, const OUTPUTS: usize, const SUBLAYERS: usize>
+    Individual<INPUTS, LAYERS, OUTPUTS, SUBLAYERS>
+{
+    /**
+     * Number of nodes in each layer, from the inputs to the outputs
+     */
+    pub fn layer_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![INPUTS];
+        sizes.extend(std::iter::repeat(SUBLAYERS).take(LAYERS + 1));
+        sizes.push(OUTPUTS);
+        sizes
+    }
+
+    /**
+     * Graphviz DOT description of the network, render with `dot -Tsvg`
+     */
+    pub fn to_dot(&self) -> String {
+        let layers = self.layers();
+        let sizes = self.layer_sizes();
+        let edges = edges(&layers);
+        let max = edges.iter().map(|edge| edge.3.abs()).fold(0.0, f32::max);
+        let mut dot = String::new();
+
+        writeln!(dot, "digraph network {{").unwrap();
+        writeln!(dot, "    rankdir=LR;").unwrap();
+        writeln!(dot, "    splines=line;").unwrap();
+        writeln!(dot, "    node [shape=circle, label=\"\", width=0.3];").unwrap();
+
+        for (layer, size) in sizes.iter().enumerate() {
+            writeln!(dot, "    subgraph cluster_{} {{", layer).unwrap();
+            writeln!(dot, "        label=\"{}\";", layer_name(layer, sizes.len())).unwrap();
+            writeln!(dot, "        color=none;").unwrap();
+            for node in 0..*size {
+                writeln!(dot, "        n{}_{};", layer, node).unwrap();
+            }
+            writeln!(dot, "    }}").unwrap();
+        }
+
+        for (layer, from, to, weight) in edges {
+            writeln!(
+                dot,
+                "    n{}_{} -> n{}_{} [color=\"{}\", penwidth={:.2}, tooltip=\"{}\"];",
+                layer,
+                from,
+                layer + 1,
+                to,
+                color(weight),
+                line_width(weight, max),
+                weight
+            )
+            .unwrap();
+        }
+
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+
+    /**
+     * Standalone SVG drawing of the network with the inputs on the left
+     */
+    pub fn to_svg(&self) -> String {
+        let layers = self.layers();
+        let sizes = self.layer_sizes();
+        let edges = edges(&layers);
+        let max = edges.iter().map(|edge| edge.3.abs()).fold(0.0, f32::max);
+
+        let tallest = sizes.iter().copied().max().unwrap_or(0) as f32;
+        let width = 2.0 * MARGIN + LAYER_SPACING * (sizes.len() - 1) as f32;
+        let height = 2.0 * MARGIN + NODE_SPACING * tallest;
+        // Layers are centered vertically
+        let position = |layer: usize, node: usize| {
+            let offset = (tallest - sizes[layer] as f32) / 2.0;
+            (
+                MARGIN + LAYER_SPACING * layer as f32,
+                MARGIN + NODE_SPACING * (offset + node as f32 + 0.5),
+            )
+        };
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+            width, height, width, height
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            "  <rect width=\"100%\" height=\"100%\" fill=\"white\"/>"
+        )
+        .unwrap();
+
+        for (layer, from, to, weight) in edges {
+            let (x1, y1) = position(layer, from);
+            let (x2, y2) = position(layer + 1, to);
+            writeln!(
+                svg,
+                "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{:.2}\"><title>{}</title></line>",
+                x1, y1, x2, y2, color(weight), line_width(weight, max), weight
+            )
+            .unwrap();
+        }
+
+        for (layer, size) in sizes.iter().enumerate() {
+            let (x, _) = position(layer, 0);
+            writeln!(
+                svg,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-family=\"sans-serif\" font-size=\"12\">{}</text>",
+                x,
+                MARGIN / 2.0,
+                layer_name(layer, sizes.len())
+            )
+            .unwrap();
+            for node in 0..*size {
+                let (x, y) = position(layer, node);
+                writeln!(
+                    svg,
+                    "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"#eeeeee\" stroke=\"black\"/>",
+                    x, y, NODE_RADIUS
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(svg, "</svg>").unwrap();
+        svg
+    }
+}
@@ -0,0 +1,120 @@
+use evolution_rust::{
+    archipelago::{Archipelago, Topology},
+    Population,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+/**
+ * Islands whose individuals are told apart by fitness, `100 * island + k`
+ */
+fn archipelago(
+    islands: usize,
+    size: usize,
+    topology: Topology,
+    migration_size: usize,
+    rng: &mut StdRng,
+) -> Archipelago<3, 1, 2, 3> {
+    let islands = (0..islands)
+        .map(|island| {
+            let mut population = Population::new(size, 2, rng);
+            for (k, individual) in population.individuals.iter_mut().enumerate() {
+                individual.fitness = (100 * island + k) as f32;
+            }
+            population
+        })
+        .collect();
+    Archipelago::new(islands, topology, 1, migration_size)
+}
+
+fn origin(fitness: f32) -> usize {
+    fitness as usize / 100
+}
+
+fn sorted_fitness(population: &Population<3, 1, 2, 3>) -> Vec<f32> {
+    let mut fitness: Vec<f32> = population.individuals.iter().map(|i| i.fitness).collect();
+    fitness.sort_by(f32::total_cmp);
+    fitness
+}
+
+#[test]
+fn ring_sends_the_fittest_to_the_next_island() {
+    let mut rng = StdRng::seed_from_u64(30);
+    let mut archipelago = archipelago(3, 5, Topology::Ring, 2, &mut rng);
+    archipelago.migrate(&mut rng);
+
+    assert_eq!(
+        sorted_fitness(&archipelago.islands[0]),
+        [2.0, 3.0, 4.0, 203.0, 204.0]
+    );
+    assert_eq!(
+        sorted_fitness(&archipelago.islands[1]),
+        [3.0, 4.0, 102.0, 103.0, 104.0]
+    );
+}
+
+#[test]
+fn fully_connected_islands_keep_their_own_elites() {
+    let mut rng = StdRng::seed_from_u64(30);
+    let mut archipelago = archipelago(5, 4, Topology::FullyConnected, 2, &mut rng);
+    archipelago.migrate(&mut rng);
+
+    for (index, island) in archipelago.islands.iter().enumerate() {
+        let fitness = sorted_fitness(island);
+        assert_eq!(fitness.len(), 4);
+        let own = (100 * index) as f32;
+        assert!(fitness.contains(&(own + 3.0)));
+        assert!(fitness.contains(&(own + 2.0)));
+        // Only the fittest migrants, those of the last other island
+        let source = if index == 4 { 3 } else { 4 };
+        let migrants: Vec<f32> = fitness
+            .into_iter()
+            .filter(|fitness| origin(*fitness) != index)
+            .collect();
+        assert_eq!(
+            migrants,
+            [(100 * source + 2) as f32, (100 * source + 3) as f32]
+        );
+    }
+}
+
+#[test]
+fn random_topology_never_migrates_to_the_source() {
+    let mut rng = StdRng::seed_from_u64(30);
+    for _ in 0..20 {
+        let mut archipelago = archipelago(3, 4, Topology::Random, 1, &mut rng);
+        archipelago.migrate(&mut rng);
+
+        let mut migrants = 0;
+        for (index, island) in archipelago.islands.iter().enumerate() {
+            let fitness = sorted_fitness(island);
+            assert!(fitness.contains(&((100 * index + 3) as f32)));
+            migrants += fitness
+                .iter()
+                .filter(|fitness| origin(**fitness) != index)
+                .count();
+        }
+        // Each island sends one, an island receiving two only takes one
+        assert!((2..=3).contains(&migrants));
+    }
+}
+
+#[test]
+fn a_single_island_is_left_alone() {
+    let mut rng = StdRng::seed_from_u64(30);
+    let mut archipelago = archipelago(1, 4, Topology::Random, 2, &mut rng);
+    let before = archipelago.clone();
+    archipelago.migrate(&mut rng);
+    assert_eq!(archipelago, before);
+}
+
+#[test]
+fn checkpoints_round_trip() {
+    let mut rng = StdRng::seed_from_u64(30);
+    let mut archipelago = archipelago(3, 4, Topology::Ring, 1, &mut rng);
+    archipelago.evolve(&mut rng);
+
+    let checkpoint = serde_json::to_string(&archipelago).unwrap();
+    let restored: Archipelago<3, 1, 2, 3> = serde_json::from_str(&checkpoint).unwrap();
+    assert_eq!(restored, archipelago);
+    assert_eq!(restored.generation(), 1);
+}
@@ -0,0 +1,142 @@
+use evolution_rust::{initializer::Initializer, Individual};
+use rand::{rngs::mock::StepRng, Rng, SeedableRng};
+
+mod generated {
+    include!("generated/brain.rs");
+}
+
+/**
+ * Individual with fixed weights, a third of them zero
+ */
+fn individual() -> Individual<10, 2, 6, 5> {
+    let mut individual = Individual::new_initialized(&Initializer::Zero, &mut StepRng::new(0, 0));
+    let weights: Vec<f32> = (0..Individual::<10, 2, 6, 5>::WEIGHT_COUNT)
+        .map(|i| {
+            if i % 3 == 0 {
+                0.0
+            } else {
+                ((i * 37 % 19) as f32 - 9.0) / 7.0
+            }
+        })
+        .collect();
+    individual.set_weights(&weights);
+    individual
+}
+
+#[test]
+fn generated_sources_are_up_to_date() {
+    let individual = individual();
+    assert_eq!(
+        individual.to_rust_source("brain"),
+        include_str!("generated/brain.rs")
+    );
+    assert_eq!(
+        individual.to_c_source("brain"),
+        include_str!("generated/brain.c")
+    );
+}
+
+#[test]
+fn generated_rust_matches_evaluate() {
+    let individual = individual();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(45);
+
+    for _ in 0..100 {
+        let inputs: [f32; 10] = std::array::from_fn(|_| rng.gen_range(-3.0..3.0));
+        let expected = individual.evaluate(inputs);
+        let actual = generated::brain(inputs);
+        assert_eq!(expected.map(f32::to_bits), actual.map(f32::to_bits));
+    }
+}
+
+/**
+ * Compiles the checked in C source with the system compiler and compares its
+ * outputs bit for bit, skipped when there is no `cc`
+ */
+#[test]
+fn generated_c_matches_evaluate() {
+    if std::process::Command::new("cc")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        eprintln!("skipping, no C compiler");
+        return;
+    }
+
+    let individual = individual();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(45);
+    let samples: Vec<[f32; 10]> = (0..100)
+        .map(|_| std::array::from_fn(|_| rng.gen_range(-3.0..3.0)))
+        .collect();
+
+    let mut driver = String::from(concat!(
+        "#include <stdio.h>\n",
+        "#include <string.h>\n",
+        "#include <stdint.h>\n",
+        "void brain(const float layer_0[10], float outputs[6]);\n",
+        "static const uint32_t samples[][10] = {\n",
+    ));
+    for inputs in &samples {
+        let bits: Vec<String> = inputs
+            .iter()
+            .map(|i| format!("{:#x}u", i.to_bits()))
+            .collect();
+        driver += &format!("    {{{}}},\n", bits.join(", "));
+    }
+    driver += concat!(
+        "};\n",
+        "int main(void) {\n",
+        "    for (size_t i = 0; i < sizeof samples / sizeof samples[0]; i++) {\n",
+        "        float inputs[10], outputs[6];\n",
+        "        uint32_t bits[6];\n",
+        "        memcpy(inputs, samples[i], sizeof inputs);\n",
+        "        brain(inputs, outputs);\n",
+        "        memcpy(bits, outputs, sizeof bits);\n",
+        "        for (size_t j = 0; j < 6; j++) printf(\"%08x \", bits[j]);\n",
+        "        printf(\"\\n\");\n",
+        "    }\n",
+        "    return 0;\n",
+        "}\n",
+    );
+
+    let directory =
+        std::env::temp_dir().join(format!("evolution_rust_codegen_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let driver_path = directory.join("driver.c");
+    let binary_path = directory.join("driver");
+    std::fs::write(&driver_path, driver).unwrap();
+
+    let status = std::process::Command::new("cc")
+        .args(["-std=c99", "-O2", "-ffp-contract=off"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/generated/brain.c"
+        ))
+        .arg(&driver_path)
+        .arg("-o")
+        .arg(&binary_path)
+        .arg("-lm")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = std::process::Command::new(&binary_path).output().unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert!(output.status.success());
+
+    let lines: Vec<String> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    assert_eq!(lines.len(), samples.len());
+    for (inputs, line) in samples.iter().zip(lines) {
+        let expected: String = individual
+            .evaluate(*inputs)
+            .iter()
+            .map(|output| format!("{:08x} ", output.to_bits()))
+            .collect();
+        assert_eq!(expected, line);
+    }
+}
@@ -0,0 +1,109 @@
+use evolution_rust::{
+    differential_evolution::{DifferentialEvolution, DifferentialStrategy},
+    initializer::Initializer,
+    ConfigChange, Individual, Population,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+/**
+ * Squared distance of the weights to 0.5, negated so higher is better
+ */
+fn evaluate(individuals: &mut [Individual<2, 1, 1, 2>]) {
+    for individual in individuals.iter_mut() {
+        individual.fitness = -individual
+            .weights()
+            .iter()
+            .map(|weight| (weight - 0.5) * (weight - 0.5))
+            .sum::<f32>();
+    }
+}
+
+fn best_target(population: &Population<2, 1, 1, 2>) -> f32 {
+    population
+        .parents()
+        .iter()
+        .map(|target| target.fitness)
+        .fold(f32::NEG_INFINITY, f32::max)
+}
+
+#[test]
+fn both_strategies_converge_on_a_quadratic() {
+    for strategy in [
+        DifferentialStrategy::Rand1Bin,
+        DifferentialStrategy::Best1Bin,
+    ] {
+        let mut rng = StdRng::seed_from_u64(26);
+        // Sparse individuals can share a zero weight that DE never moves
+        let initializer = Initializer::Dense { range: 1.0 };
+        let mut population =
+            Population::<2, 1, 1, 2>::with_initializer(20, 1, initializer, &mut rng);
+        // DE/best/1 collapses onto the best target with smaller weights
+        let settings = DifferentialEvolution {
+            strategy,
+            differential_weight: 0.8,
+            ..DifferentialEvolution::default()
+        };
+
+        for _ in 0..300 {
+            evaluate(&mut population.individuals);
+            population.evolve_differential(&settings, &mut rng);
+        }
+        assert!(best_target(&population) > -1e-3, "{:?}", strategy);
+    }
+}
+
+#[test]
+fn worse_trials_never_replace_their_target() {
+    let mut rng = StdRng::seed_from_u64(26);
+    let mut population = Population::<2, 1, 1, 2>::new(6, 1, &mut rng);
+    let settings = DifferentialEvolution::default();
+    for (index, individual) in population.individuals.iter_mut().enumerate() {
+        individual.fitness = index as f32;
+    }
+    population.evolve_differential(&settings, &mut rng);
+    let targets = population.parents().to_vec();
+
+    // Each trial is worse than its own target but better than the ones before
+    for trial in population.individuals.iter_mut() {
+        trial.fitness = trial.trial_target.unwrap() as f32 - 0.5;
+    }
+    population.individuals.reverse();
+    population.evolve_differential(&settings, &mut rng);
+
+    assert_eq!(population.parents(), targets.as_slice());
+}
+
+#[test]
+fn better_trials_replace_their_target_in_any_order() {
+    let mut rng = StdRng::seed_from_u64(26);
+    let mut population = Population::<2, 1, 1, 2>::new(6, 1, &mut rng);
+    let settings = DifferentialEvolution::default();
+    population.evolve_differential(&settings, &mut rng);
+
+    for trial in population.individuals.iter_mut() {
+        trial.fitness = 1.0;
+    }
+    let mut trials = population.individuals.clone();
+    population.individuals.reverse();
+    population.evolve_differential(&settings, &mut rng);
+
+    for trial in trials.iter_mut() {
+        trial.clear_lineage();
+    }
+    assert_eq!(population.parents(), trials.as_slice());
+}
+
+#[test]
+fn small_sizes_keep_four_targets() {
+    let mut rng = StdRng::seed_from_u64(26);
+    let mut population = Population::<2, 1, 1, 2>::new(6, 1, &mut rng);
+    let settings = DifferentialEvolution::default();
+    population.schedule(1, ConfigChange::MaxSize(3));
+
+    for _ in 0..3 {
+        evaluate(&mut population.individuals);
+        population.evolve_differential(&settings, &mut rng);
+    }
+    assert_eq!(population.parents().len(), 4);
+    assert_eq!(population.individuals.len(), 4);
+}
@@ -0,0 +1,71 @@
+use evolution_rust::{distance::DistanceMetric, initializer::Initializer, Individual};
+use rand::rngs::mock::StepRng;
+
+fn individual(weights: [f32; 3]) -> Individual<1, 1, 1, 1> {
+    let mut individual = Individual::new_initialized(&Initializer::Zero, &mut StepRng::new(0, 0));
+    individual.set_weights(&weights);
+    individual
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "{} is not {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn metrics_match_hand_computed_values() {
+    let one = individual([1.0, 2.0, 0.0]);
+    let two = individual([3.0, 0.0, 0.0]);
+
+    assert_close(one.distance(&two, DistanceMetric::SquaredEuclidean), 8.0);
+    assert_close(one.distance(&two, DistanceMetric::Normalized), 8.0 / 3.0);
+    // dot 3, norms sqrt(5) and 3
+    assert_close(
+        one.distance(&two, DistanceMetric::Cosine),
+        1.0 - 1.0 / 5f32.sqrt(),
+    );
+    assert_close(one.distance(&two, DistanceMetric::Structural), 1.0 / 3.0);
+    // Only the first weight is shared
+    let weighted = DistanceMetric::Weighted {
+        structural: 2.0,
+        weights: 0.5,
+    };
+    assert_close(one.distance(&two, weighted), 2.0 / 3.0 + 1.0);
+}
+
+#[test]
+fn metrics_are_zero_for_identical_individuals() {
+    let one = individual([1.0, -2.0, 0.0]);
+    for metric in [
+        DistanceMetric::SquaredEuclidean,
+        DistanceMetric::Normalized,
+        DistanceMetric::Cosine,
+        DistanceMetric::Structural,
+        DistanceMetric::Weighted {
+            structural: 1.0,
+            weights: 1.0,
+        },
+    ] {
+        assert_close(one.distance(&one, metric), 0.0);
+    }
+}
+
+#[test]
+fn zero_vectors_and_nothing_shared() {
+    let zero = individual([0.0; 3]);
+    let one = individual([1.0, 2.0, 0.0]);
+
+    assert_eq!(zero.distance(&zero, DistanceMetric::Cosine), 0.0);
+    assert_eq!(zero.distance(&one, DistanceMetric::Cosine), 1.0);
+    assert_eq!(one.distance(&zero, DistanceMetric::Cosine), 1.0);
+
+    let weighted = DistanceMetric::Weighted {
+        structural: 1.0,
+        weights: 1.0,
+    };
+    assert_close(zero.distance(&one, weighted), 2.0 / 3.0);
+}
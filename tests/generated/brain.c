@@ -0,0 +1,34 @@
+/* Generated by evolution_rust, do not edit */
+#include <math.h>
+
+#pragma STDC FP_CONTRACT OFF
+
+static float brain_relu(float k) { return k > 0.0f ? k : k / 2.0f; }
+static float brain_sigmoid(float k) { return 1.0f / (1.0f + expf(k)); }
+
+void brain(const float layer_0[10], float outputs[6]) {
+    float layer_1[5];
+    layer_1[0] = brain_relu(layer_0[1] * 1.2857143f + layer_0[2] * 1.1428572f + layer_0[4] * 0.85714287f + layer_0[5] * 0.71428573f + layer_0[7] * 0.42857143f + layer_0[8] * 0.2857143f);
+    layer_1[1] = brain_relu(layer_0[1] * -0.14285715f + layer_0[3] * -0.42857143f + layer_0[4] * -0.5714286f + layer_0[6] * -0.85714287f + layer_0[7] * -1.0f + layer_0[9] * -1.2857143f);
+    layer_1[2] = brain_relu(layer_0[0] * 1.2857143f + layer_0[2] * 1.0f + layer_0[3] * 0.85714287f + layer_0[5] * 0.5714286f + layer_0[6] * 0.42857143f + layer_0[8] * 0.14285715f);
+    layer_1[3] = brain_relu(layer_0[1] * -0.2857143f + layer_0[2] * -0.42857143f + layer_0[4] * -0.71428573f + layer_0[5] * -0.85714287f + layer_0[7] * -1.1428572f + layer_0[8] * -1.2857143f);
+    layer_1[4] = brain_relu(layer_0[0] * 1.1428572f + layer_0[1] * 1.0f + layer_0[3] * 0.71428573f + layer_0[4] * 0.5714286f + layer_0[6] * 0.2857143f + layer_0[7] * 0.14285715f + layer_0[9] * -0.14285715f);
+    float layer_2[5];
+    layer_2[0] = brain_relu(layer_1[0] * -0.2857143f + layer_1[2] * -0.5714286f + layer_1[3] * -0.71428573f);
+    layer_2[1] = brain_relu(layer_1[0] * -1.0f + layer_1[1] * -1.1428572f + layer_1[3] * 1.2857143f + layer_1[4] * 1.1428572f);
+    layer_2[2] = brain_relu(layer_1[1] * 0.85714287f + layer_1[2] * 0.71428573f + layer_1[4] * 0.42857143f);
+    layer_2[3] = brain_relu(layer_1[0] * 0.2857143f + layer_1[3] * -0.14285715f);
+    layer_2[4] = brain_relu(layer_1[0] * -0.42857143f + layer_1[1] * -0.5714286f + layer_1[3] * -0.85714287f + layer_1[4] * -1.0f);
+    float layer_3[5];
+    layer_3[0] = brain_relu(layer_2[1] * -1.2857143f + layer_2[2] * 1.2857143f + layer_2[4] * 1.0f);
+    layer_3[1] = brain_relu(layer_2[0] * 0.85714287f + layer_2[2] * 0.5714286f + layer_2[3] * 0.42857143f);
+    layer_3[2] = brain_relu(layer_2[0] * 0.14285715f + layer_2[3] * -0.2857143f + layer_2[4] * -0.42857143f);
+    layer_3[3] = brain_relu(layer_2[1] * -0.71428573f + layer_2[2] * -0.85714287f + layer_2[4] * -1.1428572f);
+    layer_3[4] = brain_relu(layer_2[0] * -1.2857143f + layer_2[2] * 1.1428572f + layer_2[3] * 1.0f);
+    outputs[0] = brain_sigmoid(layer_3[0] * 0.71428573f + layer_3[1] * 0.5714286f + layer_3[3] * 0.2857143f + layer_3[4] * 0.14285715f);
+    outputs[1] = brain_sigmoid(layer_3[1] * -0.14285715f + layer_3[2] * -0.2857143f + layer_3[4] * -0.5714286f);
+    outputs[2] = brain_sigmoid(layer_3[0] * -0.71428573f + layer_3[2] * -1.0f + layer_3[3] * -1.1428572f);
+    outputs[3] = brain_sigmoid(layer_3[0] * 1.2857143f + layer_3[1] * 1.1428572f + layer_3[3] * 0.85714287f + layer_3[4] * 0.71428573f);
+    outputs[4] = brain_sigmoid(layer_3[1] * 0.42857143f + layer_3[2] * 0.2857143f);
+    outputs[5] = brain_sigmoid(layer_3[0] * -0.14285715f + layer_3[2] * -0.42857143f + layer_3[3] * -0.5714286f);
+}
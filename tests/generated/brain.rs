@@ -0,0 +1,36 @@
+// Generated by evolution_rust, do not edit
+#[rustfmt::skip]
+#[allow(clippy::all)]
+pub fn brain(layer_0: [f32; 10]) -> [f32; 6] {
+    fn relu(k: f32) -> f32 { if k > 0.0 { k } else { k / 2.0 } }
+    fn sigmoid(k: f32) -> f32 { 1.0 / (1.0 + k.exp()) }
+    let layer_1 = [
+        relu(layer_0[1] * 1.2857143 + layer_0[2] * 1.1428572 + layer_0[4] * 0.85714287 + layer_0[5] * 0.71428573 + layer_0[7] * 0.42857143 + layer_0[8] * 0.2857143),
+        relu(layer_0[1] * -0.14285715 + layer_0[3] * -0.42857143 + layer_0[4] * -0.5714286 + layer_0[6] * -0.85714287 + layer_0[7] * -1.0 + layer_0[9] * -1.2857143),
+        relu(layer_0[0] * 1.2857143 + layer_0[2] * 1.0 + layer_0[3] * 0.85714287 + layer_0[5] * 0.5714286 + layer_0[6] * 0.42857143 + layer_0[8] * 0.14285715),
+        relu(layer_0[1] * -0.2857143 + layer_0[2] * -0.42857143 + layer_0[4] * -0.71428573 + layer_0[5] * -0.85714287 + layer_0[7] * -1.1428572 + layer_0[8] * -1.2857143),
+        relu(layer_0[0] * 1.1428572 + layer_0[1] * 1.0 + layer_0[3] * 0.71428573 + layer_0[4] * 0.5714286 + layer_0[6] * 0.2857143 + layer_0[7] * 0.14285715 + layer_0[9] * -0.14285715),
+    ];
+    let layer_2 = [
+        relu(layer_1[0] * -0.2857143 + layer_1[2] * -0.5714286 + layer_1[3] * -0.71428573),
+        relu(layer_1[0] * -1.0 + layer_1[1] * -1.1428572 + layer_1[3] * 1.2857143 + layer_1[4] * 1.1428572),
+        relu(layer_1[1] * 0.85714287 + layer_1[2] * 0.71428573 + layer_1[4] * 0.42857143),
+        relu(layer_1[0] * 0.2857143 + layer_1[3] * -0.14285715),
+        relu(layer_1[0] * -0.42857143 + layer_1[1] * -0.5714286 + layer_1[3] * -0.85714287 + layer_1[4] * -1.0),
+    ];
+    let layer_3 = [
+        relu(layer_2[1] * -1.2857143 + layer_2[2] * 1.2857143 + layer_2[4] * 1.0),
+        relu(layer_2[0] * 0.85714287 + layer_2[2] * 0.5714286 + layer_2[3] * 0.42857143),
+        relu(layer_2[0] * 0.14285715 + layer_2[3] * -0.2857143 + layer_2[4] * -0.42857143),
+        relu(layer_2[1] * -0.71428573 + layer_2[2] * -0.85714287 + layer_2[4] * -1.1428572),
+        relu(layer_2[0] * -1.2857143 + layer_2[2] * 1.1428572 + layer_2[3] * 1.0),
+    ];
+    [
+        sigmoid(layer_3[0] * 0.71428573 + layer_3[1] * 0.5714286 + layer_3[3] * 0.2857143 + layer_3[4] * 0.14285715),
+        sigmoid(layer_3[1] * -0.14285715 + layer_3[2] * -0.2857143 + layer_3[4] * -0.5714286),
+        sigmoid(layer_3[0] * -0.71428573 + layer_3[2] * -1.0 + layer_3[3] * -1.1428572),
+        sigmoid(layer_3[0] * 1.2857143 + layer_3[1] * 1.1428572 + layer_3[3] * 0.85714287 + layer_3[4] * 0.71428573),
+        sigmoid(layer_3[1] * 0.42857143 + layer_3[2] * 0.2857143),
+        sigmoid(layer_3[0] * -0.14285715 + layer_3[2] * -0.42857143 + layer_3[3] * -0.5714286),
+    ]
+}
@@ -0,0 +1,86 @@
+use evolution_rust::{
+    initializer::Initializer,
+    map_elites::{BehaviorDimension, MapElites},
+    Individual,
+};
+use rand::rngs::mock::StepRng;
+
+fn individual(fitness: f32, behavior: &[f32]) -> Individual<2, 1, 1, 2> {
+    let mut individual = Individual::new_initialized(&Initializer::Zero, &mut StepRng::new(0, 0));
+    individual.fitness = fitness;
+    individual.behavior = behavior.to_vec();
+    individual
+}
+
+/**
+ * Two bins over `0..1` times three bins over `0..3`
+ */
+fn grid() -> MapElites<2, 1, 1, 2> {
+    MapElites::new(vec![
+        BehaviorDimension::new(0.0, 1.0, 2),
+        BehaviorDimension::new(0.0, 3.0, 3),
+    ])
+}
+
+#[test]
+fn cells_are_indexed_row_major_and_clamped() {
+    let grid = grid();
+    assert_eq!(grid.cell_index(&[0.25, 0.5]), Some(0));
+    assert_eq!(grid.cell_index(&[0.25, 2.5]), Some(2));
+    assert_eq!(grid.cell_index(&[0.75, 1.5]), Some(4));
+    assert_eq!(grid.cell_index(&[-5.0, 10.0]), Some(2));
+    assert_eq!(grid.cell_index(&[5.0, -1.0]), Some(3));
+    assert_eq!(grid.cell_index(&[0.5]), None);
+}
+
+#[test]
+fn only_fitter_individuals_replace_an_elite() {
+    let mut grid = grid();
+    assert!(grid.insert(individual(1.0, &[0.75, 2.5])));
+    assert!(!grid.insert(individual(1.0, &[0.9, 2.9])));
+    assert!(!grid.insert(individual(0.5, &[0.75, 2.5])));
+    assert_eq!(grid.get(&[1, 2]).unwrap().fitness, 1.0);
+
+    assert!(grid.insert(individual(2.0, &[1.5, 4.0])));
+    assert_eq!(grid.get(&[1, 2]).unwrap().fitness, 2.0);
+    assert_eq!(grid.get(&[1, 2]).unwrap().behavior, [1.5, 4.0]);
+
+    assert!(!grid.insert(individual(3.0, &[0.5])));
+    assert!(grid.get(&[2, 0]).is_none());
+    assert!(grid.get(&[1]).is_none());
+}
+
+#[test]
+fn coverage_counts_filled_cells() {
+    let mut grid = grid();
+    assert_eq!(grid.coverage(), 0.0);
+
+    let stored = grid.insert_all([
+        individual(1.0, &[0.0, 0.0]),
+        individual(2.0, &[0.0, 0.1]),
+        individual(1.0, &[1.0, 1.0]),
+        individual(1.0, &[0.0, 2.0]),
+    ]);
+    assert_eq!(stored, 4);
+    assert_eq!(grid.coverage(), 0.5);
+    assert_eq!(grid.best().unwrap().fitness, 2.0);
+}
+
+#[test]
+fn dimensions_without_bins_are_rejected() {
+    let json = r#"{"min":0.0,"max":1.0,"bins":0}"#;
+    assert!(serde_json::from_str::<BehaviorDimension>(json).is_err());
+
+    let dimension = BehaviorDimension::new(0.0, 1.0, 4);
+    let json = serde_json::to_string(&dimension).unwrap();
+    assert_eq!(
+        serde_json::from_str::<BehaviorDimension>(&json).unwrap(),
+        dimension
+    );
+}
+
+#[test]
+#[should_panic(expected = "at least one bin")]
+fn new_needs_a_bin() {
+    BehaviorDimension::new(0.0, 1.0, 0);
+}
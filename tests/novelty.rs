@@ -0,0 +1,53 @@
+use evolution_rust::{novelty::NoveltyArchive, Population};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn novelty_averages_the_nearest_neighbors() {
+    let mut archive = NoveltyArchive::new(2, 1, 10);
+    archive.add(vec![0.0, 2.0]);
+
+    let others = [vec![1.0, 0.0], vec![3.0, 0.0]];
+    let novelty = archive.novelty(&[0.0, 0.0], others.iter().map(Vec::as_slice));
+    // Distances 1, 3 and 2, the nearest two are 1 and 2
+    assert_eq!(novelty, 1.5);
+
+    assert_eq!(NoveltyArchive::new(2, 1, 10).novelty(&[0.0], []), 0.0);
+}
+
+#[test]
+fn full_archives_drop_the_oldest_behaviors() {
+    let mut archive = NoveltyArchive::new(1, 1, 2);
+    for behavior in 0..5 {
+        archive.add(vec![behavior as f32]);
+    }
+    assert_eq!(archive.behaviors, [vec![3.0], vec![4.0]]);
+}
+
+#[test]
+fn novelty_selects_without_touching_fitness() {
+    let mut rng = StdRng::seed_from_u64(28);
+    let mut population = Population::<3, 1, 2, 3>::new(3, 1, &mut rng);
+    for (individual, (fitness, behavior)) in
+        population
+            .individuals
+            .iter_mut()
+            .zip([(10.0, 0.0), (0.0, 0.1), (0.0, 5.0)])
+    {
+        individual.fitness = fitness;
+        individual.behavior = vec![behavior];
+    }
+    let most_novel = population.individuals[2].clone();
+
+    let novelty = population.apply_novelty(1.0);
+    assert_eq!(novelty, [2.55, 2.5, 4.95]);
+    assert_eq!(population.individuals[0].fitness, 10.0);
+    assert_eq!(population.individuals[2].selection_score, Some(4.95));
+    assert_eq!(population.novelty_archive.behaviors, [vec![5.0]]);
+
+    population.evolve(&mut rng);
+    assert_eq!(population.history().latest().unwrap().best_fitness, 10.0);
+    assert_eq!(population.hall_of_fame.best().unwrap().fitness, 10.0);
+    // The only elite is the most novel individual
+    assert_eq!(population.individuals[0].weights(), most_novel.weights());
+    assert_eq!(population.individuals[0].selection_score, None);
+}
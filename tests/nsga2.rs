@@ -0,0 +1,68 @@
+use evolution_rust::nsga2::{crowding_distance, dominates, non_dominated_sort};
+
+fn sorted(mut front: Vec<usize>) -> Vec<usize> {
+    front.sort();
+    front
+}
+
+#[test]
+fn dominance_needs_one_strict_improvement() {
+    assert!(dominates(&[2.0, 1.0], &[1.0, 1.0]));
+    assert!(!dominates(&[1.0, 1.0], &[1.0, 1.0]));
+    assert!(!dominates(&[2.0, 0.0], &[1.0, 1.0]));
+    assert!(!dominates(&[1.0, 1.0], &[2.0, 1.0]));
+}
+
+#[test]
+fn sorts_a_known_two_objective_population_into_fronts() {
+    let objectives = vec![
+        vec![4.0, 1.0], // 0: front 0
+        vec![3.0, 3.0], // 1: front 0
+        vec![1.0, 4.0], // 2: front 0
+        vec![2.0, 2.0], // 3: dominated by 1
+        vec![3.0, 1.0], // 4: dominated by 0 and 1
+        vec![1.0, 1.0], // 5: dominated by 3 and 4
+        vec![3.0, 3.0], // 6: duplicate of 1, also front 0
+    ];
+
+    let fronts: Vec<Vec<usize>> = non_dominated_sort(&objectives)
+        .into_iter()
+        .map(sorted)
+        .collect();
+    assert_eq!(fronts, vec![vec![0, 1, 2, 6], vec![3, 4], vec![5]]);
+}
+
+#[test]
+fn empty_population_has_no_fronts() {
+    assert!(non_dominated_sort(&[]).is_empty());
+    assert!(crowding_distance(&[], &[]).is_empty());
+}
+
+#[test]
+fn crowding_distance_is_infinite_at_the_boundaries() {
+    let objectives = vec![
+        vec![0.0, 4.0],
+        vec![1.0, 3.0],
+        vec![3.0, 1.0],
+        vec![4.0, 0.0],
+    ];
+    // Deliberately not in objective order
+    let front = [2, 0, 3, 1];
+
+    let distance = crowding_distance(&objectives, &front);
+    assert_eq!(distance[1], f32::INFINITY);
+    assert_eq!(distance[2], f32::INFINITY);
+    // Neighbours of 1 are 0 and 2, spanning 3 of the range 4 in both objectives
+    assert!((distance[3] - 1.5).abs() < 1e-6);
+    // Neighbours of 2 are 1 and 3, also spanning 3
+    assert!((distance[0] - 1.5).abs() < 1e-6);
+}
+
+#[test]
+fn crowding_distance_of_identical_objectives_only_marks_boundaries() {
+    let objectives = vec![vec![1.0, 1.0]; 3];
+    let distance = crowding_distance(&objectives, &[0, 1, 2]);
+
+    assert_eq!(distance.iter().filter(|d| d.is_infinite()).count(), 2);
+    assert!(distance.contains(&0.0));
+}
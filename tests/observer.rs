@@ -0,0 +1,87 @@
+use std::{cell::RefCell, io, rc::Rc};
+
+use evolution_rust::{
+    observer::{EvolutionObserver, JsonLinesObserver},
+    statistics::GenerationStatistics,
+    Individual, Population,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+struct BrokenWriter;
+
+impl io::Write for BrokenWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn shared_observers_stay_reachable() {
+    let mut rng = StdRng::seed_from_u64(32);
+    let mut population = Population::<3, 1, 2, 3>::new(6, 2, &mut rng);
+
+    let log = Rc::new(RefCell::new(JsonLinesObserver::new(Vec::new())));
+    let broken = Rc::new(RefCell::new(JsonLinesObserver::new(BrokenWriter)));
+    population.add_observer(log.clone());
+    population.add_observer(broken.clone());
+
+    population.evolve(&mut rng);
+    population.clear_observers();
+
+    assert!(log.borrow().error().is_none());
+    assert_eq!(
+        broken.borrow().error().map(io::Error::kind),
+        Some(io::ErrorKind::BrokenPipe)
+    );
+
+    let log = Rc::try_unwrap(log).ok().unwrap().into_inner().into_inner();
+    let lines = String::from_utf8(log).unwrap();
+    assert!(lines
+        .lines()
+        .any(|line| line.contains("\"event\":\"generation_end\"")));
+}
+
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl EvolutionObserver<3, 1, 2, 3> for Events {
+    fn on_generation_start(&mut self, generation: usize) {
+        self.0.push(format!("start {generation}"));
+    }
+
+    fn on_evaluated(&mut self, generation: usize, _individuals: &[Individual<3, 1, 2, 3>]) {
+        self.0.push(format!("evaluated {generation}"));
+    }
+
+    fn on_generation_end(&mut self, statistics: &GenerationStatistics) {
+        self.0.push(format!("end {}", statistics.generation));
+    }
+}
+
+#[test]
+fn generations_start_before_they_are_evaluated() {
+    let mut rng = StdRng::seed_from_u64(32);
+    let mut population = Population::<3, 1, 2, 3>::new(6, 2, &mut rng);
+
+    let events = Rc::new(RefCell::new(Events::default()));
+    population.add_observer(events.clone());
+    population.evolve(&mut rng);
+    population.evolve(&mut rng);
+
+    assert_eq!(
+        events.borrow().0,
+        [
+            "start 0",
+            "evaluated 0",
+            "end 0",
+            "start 1",
+            "evaluated 1",
+            "end 1",
+            "start 2"
+        ]
+    );
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use evolution_rust::{
+    onnx::{INPUT_NAME, OUTPUT_NAME},
+    Individual,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+enum Value {
+    Varint(u64),
+    Fixed32(u32),
+    Bytes(Vec<u8>),
+}
+
+/**
+ * Decodes the fields of a protobuf message, only supporting the wire types the
+ * exporter emits
+ */
+fn decode(mut bytes: &[u8]) -> Vec<(u64, Value)> {
+    fn varint(bytes: &mut &[u8]) -> u64 {
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let byte = bytes[0];
+            *bytes = &bytes[1..];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte < 0x80 {
+                return value;
+            }
+            shift += 7;
+        }
+    }
+
+    let mut fields = Vec::new();
+    while !bytes.is_empty() {
+        let key = varint(&mut bytes);
+        let value = match key & 7 {
+            0 => Value::Varint(varint(&mut bytes)),
+            2 => {
+                let length = varint(&mut bytes) as usize;
+                let (value, rest) = bytes.split_at(length);
+                bytes = rest;
+                Value::Bytes(value.to_vec())
+            }
+            5 => {
+                let (value, rest) = bytes.split_at(4);
+                bytes = rest;
+                Value::Fixed32(u32::from_le_bytes(value.try_into().unwrap()))
+            }
+            wire_type => panic!("Unexpected wire type {}", wire_type),
+        };
+        fields.push((key >> 3, value));
+    }
+    fields
+}
+
+fn bytes(fields: &[(u64, Value)], field: u64) -> Vec<&[u8]> {
+    fields
+        .iter()
+        .filter_map(|(number, value)| match value {
+            Value::Bytes(bytes) if *number == field => Some(bytes.as_slice()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn varints(fields: &[(u64, Value)], field: u64) -> Vec<u64> {
+    fields
+        .iter()
+        .filter_map(|(number, value)| match value {
+            Value::Varint(value) if *number == field => Some(*value),
+            _ => None,
+        })
+        .collect()
+}
+
+fn string(fields: &[(u64, Value)], field: u64) -> String {
+    String::from_utf8(bytes(fields, field)[0].to_vec()).unwrap()
+}
+
+/// Row-major matrix
+#[derive(Clone)]
+struct Tensor {
+    rows: usize,
+    columns: usize,
+    data: Vec<f32>,
+}
+
+impl Tensor {
+    fn map(&self, function: impl Fn(f32) -> f32) -> Tensor {
+        Tensor {
+            data: self.data.iter().copied().map(function).collect(),
+            ..*self
+        }
+    }
+
+    fn matmul(&self, other: &Tensor) -> Tensor {
+        assert_eq!(self.columns, other.rows);
+        let mut data = vec![0.0; self.rows * other.columns];
+        for row in 0..self.rows {
+            for column in 0..other.columns {
+                data[row * other.columns + column] = (0..self.columns)
+                    .map(|k| {
+                        self.data[row * self.columns + k] * other.data[k * other.columns + column]
+                    })
+                    .sum();
+            }
+        }
+        Tensor {
+            rows: self.rows,
+            columns: other.columns,
+            data,
+        }
+    }
+}
+
+/**
+ * Runs the graph of an exported model on a batch of inputs
+ */
+fn run(model: &[u8], input: Tensor) -> Tensor {
+    let model = decode(model);
+    assert_eq!(varints(&model, 1), vec![8]);
+    let opset = decode(bytes(&model, 8)[0]);
+    assert_eq!(varints(&opset, 2), vec![13]);
+
+    let graph = decode(bytes(&model, 7)[0]);
+    assert_eq!(string(&decode(bytes(&graph, 11)[0]), 1), INPUT_NAME);
+    assert_eq!(string(&decode(bytes(&graph, 12)[0]), 1), OUTPUT_NAME);
+
+    let mut values = HashMap::new();
+    values.insert(INPUT_NAME.to_string(), input);
+    for initializer in bytes(&graph, 5) {
+        let tensor = decode(initializer);
+        let dims = varints(&tensor, 1);
+        assert_eq!(varints(&tensor, 2), vec![1]);
+        let data = bytes(&tensor, 9)[0]
+            .chunks(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        values.insert(
+            string(&tensor, 8),
+            Tensor {
+                rows: dims[0] as usize,
+                columns: dims[1] as usize,
+                data,
+            },
+        );
+    }
+
+    for node in bytes(&graph, 1) {
+        let node = decode(node);
+        let inputs: Vec<&Tensor> = bytes(&node, 1)
+            .into_iter()
+            .map(|name| &values[std::str::from_utf8(name).unwrap()])
+            .collect();
+        let output = match string(&node, 4).as_str() {
+            "MatMul" => inputs[0].matmul(inputs[1]),
+            "LeakyRelu" => {
+                let attribute = decode(bytes(&node, 5)[0]);
+                assert_eq!(string(&attribute, 1), "alpha");
+                let alpha = match attribute.iter().find(|(number, _)| *number == 2) {
+                    Some((_, Value::Fixed32(bits))) => f32::from_bits(*bits),
+                    _ => panic!("LeakyRelu without alpha"),
+                };
+                inputs[0].map(|x| if x >= 0.0 { x } else { alpha * x })
+            }
+            "Neg" => inputs[0].map(|x| -x),
+            "Sigmoid" => inputs[0].map(|x| 1.0 / (1.0 + (-x).exp())),
+            op_type => panic!("Unsupported operator {}", op_type),
+        };
+        values.insert(string(&node, 2), output);
+    }
+
+    values.remove(OUTPUT_NAME).unwrap()
+}
+
+#[test]
+fn exported_model_matches_evaluate() {
+    let mut rng = StdRng::seed_from_u64(44);
+    let individual = Individual::<10, 2, 6, 5>::new_random(&mut rng);
+
+    let samples: Vec<[f32; 10]> = (0..16)
+        .map(|_| std::array::from_fn(|_| rng.gen_range(-2.0..2.0)))
+        .collect();
+    let input = Tensor {
+        rows: samples.len(),
+        columns: 10,
+        data: samples.iter().flatten().copied().collect(),
+    };
+
+    let output = run(&individual.to_onnx(), input);
+    assert_eq!((output.rows, output.columns), (samples.len(), 6));
+    for (sample, row) in samples.iter().zip(output.data.chunks(6)) {
+        for (expected, actual) in individual.evaluate(*sample).iter().zip(row) {
+            assert!(
+                (expected - actual).abs() < 1e-5,
+                "{} != {}",
+                expected,
+                actual
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use evolution_rust::{schedule::Schedule, ConfigChange, Population};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn zero_species_keeps_one() {
+    let mut rng = StdRng::seed_from_u64(39);
+    let mut population = Population::<3, 1, 2, 3>::new(6, 0, &mut rng);
+    assert_eq!(population.max_species(), 1);
+
+    population.set_max_species(0);
+    assert_eq!(population.max_species(), 1);
+
+    population.schedule(0, ConfigChange::MaxSpecies(0));
+    population.evolve(&mut rng);
+    assert_eq!(population.max_species(), 1);
+    assert_eq!(population.individuals.len(), 6);
+}
+
+#[test]
+fn empty_population_grows_from_random_individuals() {
+    let mut rng = StdRng::seed_from_u64(39);
+    let mut population = Population::<3, 1, 2, 3>::new_from_individuals(2, Vec::new());
+    population.set_max_size(10);
+
+    population.evolve(&mut rng);
+    assert_eq!(population.individuals.len(), 10);
+
+    population.evolve(&mut rng);
+    assert_eq!(population.individuals.len(), 10);
+}
+
+#[test]
+fn one_fifth_rule_survives_reordering() {
+    let mut rng = StdRng::seed_from_u64(40);
+    let mut population = Population::<3, 1, 2, 3>::new(10, 2, &mut rng);
+    population.mutation_strength = Schedule::OneFifth {
+        value: 0.1,
+        factor: 2.0,
+        min: 0.01,
+        max: 1.0,
+    };
+    population.evolve(&mut rng);
+
+    let offspring = population
+        .individuals
+        .iter()
+        .filter(|individual| individual.parent_fitness.is_some())
+        .count();
+    assert!(offspring > 0);
+    for individual in population.individuals.iter_mut() {
+        if let Some(parent) = individual.parent_fitness {
+            individual.fitness = parent + 1.0;
+        }
+    }
+    // Migration between islands sorts the population like this
+    population.individuals.reverse();
+
+    population.evolve(&mut rng);
+    assert_eq!(population.mutation_strength.value(2), 0.2);
+}
+
+#[test]
+fn checkpoints_restore_adapted_schedules() {
+    let mut rng = StdRng::seed_from_u64(40);
+    let mut population = Population::<3, 1, 2, 3>::new(6, 2, &mut rng);
+    population.mutation_count = Schedule::OneFifth {
+        value: 10.0,
+        factor: 1.5,
+        min: 1.0,
+        max: 50.0,
+    };
+    population.schedule(5, ConfigChange::MaxSize(8));
+    population.evolve(&mut rng);
+    population.evolve(&mut rng);
+
+    let checkpoint = serde_json::to_string(&population).unwrap();
+    let restored: Population<3, 1, 2, 3> = serde_json::from_str(&checkpoint).unwrap();
+
+    assert_eq!(restored, population);
+    assert_eq!(restored.mutation_count, population.mutation_count);
+    assert_ne!(
+        restored.mutation_count,
+        Schedule::OneFifth {
+            value: 10.0,
+            factor: 1.5,
+            min: 1.0,
+            max: 50.0,
+        }
+    );
+    assert_eq!(restored.generation(), 2);
+    assert_eq!(restored.scheduled_changes(), population.scheduled_changes());
+}
@@ -0,0 +1,64 @@
+use evolution_rust::{initializer::Initializer, Individual};
+use nalgebra::DMatrix;
+use rand::rngs::mock::StepRng;
+
+const PROBES: [[f32; 2]; 4] = [[0.0, 0.0], [1.0, -1.0], [-2.0, 0.5], [3.0, 3.0]];
+
+/**
+ * The second neuron of the first hidden layer has no incoming connections and
+ * the second neuron of the second hidden layer no outgoing ones
+ */
+fn individual() -> Individual<2, 1, 1, 2> {
+    let mut individual = Individual::new_initialized(&Initializer::Zero, &mut StepRng::new(0, 0));
+    individual.set_layers(&[
+        DMatrix::from_row_slice(2, 2, &[1.0, 0.0, -0.5, 0.0]),
+        DMatrix::from_row_slice(2, 2, &[0.8, 0.3, 0.7, 0.0]),
+        DMatrix::from_row_slice(2, 1, &[1.2, 0.0]),
+    ]);
+    individual
+}
+
+#[test]
+fn dead_neurons_are_removed_without_changing_outputs() {
+    let original = individual();
+    let mut individual = original.clone();
+    assert_eq!(individual.dead_neuron_count(), 0);
+
+    assert_eq!(individual.remove_dead_neurons(), 2);
+    assert_eq!(individual.dead_neuron_count(), 2);
+    assert_eq!(
+        individual.connection_count(),
+        original.connection_count() - 2
+    );
+    assert_eq!(individual.max_output_deviation(&original, &PROBES), 0.0);
+
+    assert_eq!(individual.remove_dead_neurons(), 0);
+}
+
+#[test]
+fn simplify_reports_what_it_removed() {
+    let original = individual();
+    let mut individual = original.clone();
+
+    let report = individual.simplify(0.4, &PROBES, 0.0).unwrap();
+    // The 0.3 weight is pruned and the unfed neuron loses its 0.7 connection
+    assert_eq!(report.pruned_connections, 1);
+    assert_eq!(report.dead_connections, 1);
+    assert_eq!(report.dead_neurons, 2);
+    assert_eq!(report.max_deviation, 0.0);
+    assert_eq!(
+        individual.connection_count(),
+        original.connection_count() - 2
+    );
+}
+
+#[test]
+fn simplify_rolls_back_when_outputs_move_too_much() {
+    let original = individual();
+    let mut individual = original.clone();
+
+    let report = individual.simplify(1.0, &PROBES, 1e-3).unwrap_err();
+    assert!(report.max_deviation > 1e-3);
+    assert!(report.removed_connections() > 0);
+    assert_eq!(individual, original);
+}
@@ -0,0 +1,68 @@
+use evolution_rust::{
+    initializer::Initializer,
+    quantization::{from_fixed, leaky_relu, sigmoid, to_fixed, QuantizedWeight, ONE},
+    Individual,
+};
+use rand::{Rng, SeedableRng};
+
+fn exact_sigmoid(value: f32) -> f32 {
+    1.0 / (1.0 + value.exp())
+}
+
+#[test]
+fn sigmoid_is_a_mirrored_approximation() {
+    assert_eq!(sigmoid(0), ONE / 2);
+    assert_eq!(sigmoid(to_fixed(10.0)), 0);
+    assert_eq!(sigmoid(to_fixed(-10.0)), ONE);
+
+    for step in -800..=800 {
+        let value = to_fixed(step as f32 / 100.0);
+        assert_eq!(sigmoid(value) + sigmoid(-value), ONE, "at {}", value);
+        // PLAN jumps up by about 0.004 where its pieces meet at 2.375
+        assert!(
+            sigmoid(value + ONE / 100) <= sigmoid(value) + ONE / 200,
+            "at {}",
+            value
+        );
+        let error = (from_fixed(sigmoid(value)) - exact_sigmoid(from_fixed(value))).abs();
+        assert!(error < 0.02, "{} off at {}", error, from_fixed(value));
+    }
+}
+
+#[test]
+fn leaky_relu_halves_negative_values() {
+    assert_eq!(leaky_relu(ONE), ONE);
+    assert_eq!(leaky_relu(0), 0);
+    assert_eq!(leaky_relu(-ONE), -ONE / 2);
+}
+
+fn assert_close_to_evaluate<W: QuantizedWeight>(seed: u64, tolerance: f32) {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let individual: Individual<10, 2, 6, 5> =
+        Individual::new_initialized(&Initializer::Xavier, &mut rng);
+    let samples: Vec<[f32; 10]> = (0..200)
+        .map(|_| std::array::from_fn(|_| rng.gen_range(-3.0..3.0)))
+        .collect();
+
+    let report = individual.quantize::<W>().deviation(&individual, &samples);
+    assert!(
+        report.max_deviation < tolerance,
+        "max deviation {}",
+        report.max_deviation
+    );
+}
+
+#[test]
+fn i16_outputs_stay_close_to_evaluate() {
+    for seed in 0..10 {
+        assert_close_to_evaluate::<i16>(seed, 0.02);
+    }
+}
+
+#[test]
+fn i8_outputs_stay_close_to_evaluate() {
+    for seed in 0..10 {
+        // The weights only keep about two significant digits
+        assert_close_to_evaluate::<i8>(seed, 0.05);
+    }
+}
@@ -0,0 +1,206 @@
+use evolution_rust::{
+    schedule::Schedule,
+    space::{
+        evaluate_in_arenas,
+        sensors::{self, RAY_FOOD, RAY_NOTHING, RAY_WALL},
+        Arena, ArenaGenerator, Bias, Gradients, RayCasts, RelativeVelocity, Sensors, Spaceship,
+        Vector, World, FOOD_LOCATIONS, FOOD_REWARD,
+    },
+    Population,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn headless_rounds_are_deterministic() {
+    let mut rng = StdRng::seed_from_u64(48);
+    let population = Population::<10, 2, 6, 5>::new(8, 3, &mut rng);
+
+    let mut first = World::new(population.individuals.clone());
+    let mut second = World::new(population.individuals);
+    assert_eq!(first.run(200), second.run(200));
+    assert_eq!(first, second);
+
+    for ship in first.ships.iter() {
+        assert_eq!(ship.brain.objectives[0], ship.food_eaten() as f32);
+        assert!(ship.brain.fitness >= ship.food_eaten() as f32 * FOOD_REWARD);
+    }
+    assert_eq!(
+        first.food_eaten.iter().sum::<u32>() as usize,
+        first
+            .ships
+            .iter()
+            .map(|ship| ship.food_eaten())
+            .sum::<usize>()
+    );
+}
+
+#[test]
+fn headless_evolution() {
+    let mut rng = StdRng::seed_from_u64(48);
+    let mut population = Population::<10, 2, 6, 5>::new(8, 3, &mut rng);
+
+    for _ in 0..3 {
+        let mut world = World::new(population.individuals);
+        world.run(100);
+        assert_eq!(world.arena.food.len(), FOOD_LOCATIONS.len());
+        population.individuals = world.into_brains().collect();
+        population.evolve(&mut rng);
+    }
+
+    assert_eq!(population.history().len(), 3);
+    assert_eq!(population.individuals.len(), 8);
+}
+
+#[test]
+fn arenas_round_trip_through_json() {
+    let arena = Arena::original();
+    assert_eq!(Arena::from_json(&arena.to_json()).unwrap(), arena);
+}
+
+#[test]
+fn generated_arenas_depend_only_on_the_seed() {
+    let generator = ArenaGenerator::default();
+    let arena = generator.generate(7);
+    assert_eq!(arena, generator.generate(7));
+    assert_ne!(arena, generator.generate(8));
+
+    assert_eq!(arena.food.len(), generator.food_count);
+    assert_eq!(
+        arena.walls.len(),
+        Arena::border(generator.half_size, generator.wall_spacing).len() + generator.obstacle_count
+    );
+    for food in arena.food.iter() {
+        assert!(arena
+            .walls
+            .iter()
+            .all(|wall| (wall - food).norm() >= generator.wall_clearance));
+    }
+}
+
+#[test]
+fn cramped_arenas_are_generated_with_fewer_objects() {
+    // Smaller than the wall clearance
+    let tiny = ArenaGenerator {
+        half_size: 4.0,
+        ..ArenaGenerator::default()
+    };
+    tiny.generate(0);
+
+    // Everything inside the walls is within the spawn clearance
+    let small = ArenaGenerator {
+        half_size: 10.0,
+        ..ArenaGenerator::default()
+    };
+    let arena = small.generate(0);
+    assert_eq!(
+        arena.walls.len(),
+        Arena::border(small.half_size, small.wall_spacing).len()
+    );
+}
+
+#[test]
+#[should_panic(expected = "The wall spacing has to be positive")]
+fn zero_wall_spacing_is_rejected() {
+    ArenaGenerator {
+        wall_spacing: 0.0,
+        ..ArenaGenerator::default()
+    }
+    .generate(0);
+}
+
+#[test]
+fn multi_arena_evaluation_averages_fitness() {
+    let mut rng = StdRng::seed_from_u64(49);
+    let population = Population::<10, 2, 6, 5>::new(4, 2, &mut rng);
+    let arenas = ArenaGenerator::default().generate_many(0, 3);
+
+    let mut brains = population.individuals.clone();
+    evaluate_in_arenas(&mut brains, &arenas, &Gradients, 100);
+
+    let mut expected = vec![0.0; brains.len()];
+    for arena in arenas.iter() {
+        let mut world = World::in_arena(arena.clone(), population.individuals.clone());
+        world.run(100);
+        for (total, ship) in expected.iter_mut().zip(world.ships) {
+            *total += ship.brain.fitness / arenas.len() as f32;
+        }
+    }
+
+    for (brain, expected) in brains.iter().zip(expected) {
+        assert!((brain.fitness - expected).abs() < 1e-5);
+        assert_eq!(brain.behavior.len(), 2 * arenas.len());
+        assert_eq!(brain.objectives.len(), 2);
+    }
+}
+
+#[test]
+fn arena_evaluation_keeps_adaptive_schedules_adapting() {
+    let mut rng = StdRng::seed_from_u64(40);
+    let mut population = Population::<10, 2, 6, 5>::new(8, 2, &mut rng);
+    population.mutation_strength = Schedule::OneFifth {
+        value: 0.1,
+        factor: 2.0,
+        min: 0.01,
+        max: 1.0,
+    };
+    let arenas = ArenaGenerator::default().generate_many(0, 2);
+
+    evaluate_in_arenas(&mut population.individuals, &arenas, &Gradients, 100);
+    population.evolve(&mut rng);
+    evaluate_in_arenas(&mut population.individuals, &arenas, &Gradients, 100);
+    assert!(population
+        .individuals
+        .iter()
+        .any(|individual| individual.parent_fitness.is_some()));
+
+    population.evolve(&mut rng);
+    assert_ne!(population.mutation_strength.value(2), 0.1);
+}
+
+#[test]
+fn rays_report_the_nearest_object() {
+    let arena = Arena {
+        food: vec![Vector::new(10.0, 0.0), Vector::new(20.0, 0.0)],
+        walls: vec![Vector::new(0.0, 10.0)],
+    };
+    let mut rng = StdRng::seed_from_u64(50);
+    let brain = Population::<6, 1, 4, 3>::new(1, 1, &mut rng)
+        .individuals
+        .remove(0);
+    let ship = Spaceship::new(brain, arena.food.len());
+
+    let rays = RayCasts::<3> {
+        field_of_view: std::f32::consts::PI,
+        range: 20.0,
+    };
+    let readings = sensors::sense(&rays, &ship, &arena);
+
+    assert_eq!(&readings[0..2], &[0.0, RAY_NOTHING]);
+    assert!((readings[2] - (1.0 - (10.0 - 2.0f32.sqrt()) / 20.0)).abs() < 1e-5);
+    assert_eq!(readings[3], RAY_FOOD);
+    assert!((readings[4] - (1.0 - (10.0 - 10.0f32.sqrt()) / 20.0)).abs() < 1e-5);
+    assert_eq!(readings[5], RAY_WALL);
+}
+
+type Layout = (RayCasts<8>, RelativeVelocity, Bias);
+
+#[test]
+fn sensor_layouts_set_the_brain_inputs() {
+    assert_eq!(<Layout as Sensors>::INPUTS, 22);
+
+    let mut rng = StdRng::seed_from_u64(50);
+    let population = Population::<{ <Layout as Sensors>::INPUTS }, 1, 4, 6>::new(4, 2, &mut rng);
+    let mut world = World::with_sensors(
+        ArenaGenerator::default().generate(50),
+        Layout::default(),
+        population.individuals,
+    );
+    world.run(50);
+
+    let readings = sensors::sense(&world.sensors, &world.ships[0], &world.arena);
+    assert_eq!(readings[21], 1.0);
+    for ray in readings[..16].chunks(2) {
+        assert!((0.0..=1.0).contains(&ray[0]));
+        assert!([RAY_FOOD, RAY_WALL, RAY_NOTHING].contains(&ray[1]));
+    }
+}
@@ -0,0 +1,51 @@
+use evolution_rust::{initializer::Initializer, Individual};
+use nalgebra::DMatrix;
+use rand::rngs::mock::StepRng;
+
+const POSITIVE: &str = "#1f77b4";
+const NEGATIVE: &str = "#d62728";
+
+/**
+ * Five positive and one negative weight
+ */
+fn individual() -> Individual<2, 1, 1, 2> {
+    let mut individual = Individual::new_initialized(&Initializer::Zero, &mut StepRng::new(0, 0));
+    individual.set_layers(&[
+        DMatrix::from_row_slice(2, 2, &[1.0, 0.0, -0.5, 0.0]),
+        DMatrix::from_row_slice(2, 2, &[0.8, 0.3, 0.7, 0.0]),
+        DMatrix::from_row_slice(2, 1, &[1.2, 0.0]),
+    ]);
+    individual
+}
+
+#[test]
+fn dot_has_every_node_and_one_edge_per_weight() {
+    let individual = individual();
+    let sizes = individual.layer_sizes();
+    assert_eq!(sizes, [2, 2, 2, 1]);
+    let dot = individual.to_dot();
+
+    for (layer, size) in sizes.iter().enumerate() {
+        for node in 0..*size {
+            assert!(dot.contains(&format!("        n{}_{};\n", layer, node)));
+        }
+    }
+    let edges: Vec<&str> = dot.lines().filter(|line| line.contains(" -> ")).collect();
+    assert_eq!(edges.len(), individual.connection_count());
+    assert_eq!(edges.iter().filter(|e| e.contains(POSITIVE)).count(), 5);
+    assert_eq!(edges.iter().filter(|e| e.contains(NEGATIVE)).count(), 1);
+    assert!(dot.contains(&format!("n0_1 -> n1_0 [color=\"{}\"", NEGATIVE)));
+}
+
+#[test]
+fn svg_has_every_node_and_one_line_per_weight() {
+    let individual = individual();
+    let svg = individual.to_svg();
+
+    let nodes: usize = individual.layer_sizes().iter().sum();
+    assert_eq!(svg.matches("<circle").count(), nodes);
+    assert_eq!(svg.matches("<line").count(), individual.connection_count());
+    assert_eq!(svg.matches(&format!("stroke=\"{}\"", POSITIVE)).count(), 5);
+    assert_eq!(svg.matches(&format!("stroke=\"{}\"", NEGATIVE)).count(), 1);
+    assert!(svg.starts_with("<svg") && svg.trim_end().ends_with("</svg>"));
+}